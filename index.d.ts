@@ -3,16 +3,555 @@
 
 /* auto-generated by NAPI-RS */
 
+/** Options for `Repository.blame`. */
+export interface BlameOptions {
+  /** The most recent commit to consider. Defaults to HEAD. */
+  newestCommit?: Oid
+  /** The oldest commit to consider. Defaults to the first commit without a parent. */
+  oldestCommit?: Oid
+  /** The first line in the file to blame (1-based). */
+  minLine?: number
+  /** The last line in the file to blame (1-based, inclusive). */
+  maxLine?: number
+  /** Follow only the first parent of merge commits. */
+  firstParent?: boolean
+  ignoreWhitespace?: boolean
+}
+export interface BlameHunk {
+  /** The commit where the lines were last changed. */
+  finalCommitId: Oid
+  finalStartLine: number
+  finalSignature: Signature
+  /** The commit where the lines were found, before following renames and copies. */
+  origCommitId: Oid
+  origPath?: string
+  origStartLine: number
+  origSignature: Signature
+  linesInHunk: number
+  /** Whether the hunk has been tracked to the boundary commit (the oldest commit). */
+  boundary: boolean
+}
+export const enum BranchType {
+  Local = 0,
+  Remote = 1,
+  All = 2
+}
+export interface AheadBehind {
+  /** The number of commits reachable from local only. */
+  ahead: number
+  /** The number of commits reachable from upstream only. */
+  behind: number
+}
+export interface BranchTracking {
+  /** The short name of the local branch. */
+  name: string
+  /** The full name of the upstream reference, or null if the branch has no upstream. */
+  upstream?: string
+  /** The upstream is configured, but its reference does not exist. */
+  gone: boolean
+  ahead: number
+  behind: number
+}
+/** Options for operations updating the working directory. Defaults to a safe checkout. */
+export interface CheckoutOptions {
+  /**
+  * Take any action necessary to get the working directory to match the target,
+  * overwriting local changes.
+  */
+  force?: boolean
+  /** Proceed with the checkout even if there are conflicts, writing the files that can be updated. */
+  allowConflicts?: boolean
+  removeUntracked?: boolean
+  removeIgnored?: boolean
+  /** Use the "ours" side of conflicted files. */
+  useOurs?: boolean
+  /** Use the "theirs" side of conflicted files. */
+  useTheirs?: boolean
+  /** Write conflict markers including the common ancestor (`merge.conflictStyle=diff3`). */
+  conflictStyleDiff3?: boolean
+  /** Only check out the given paths. */
+  paths?: Array<string>
+  /** Report what would be done, without touching the working directory. */
+  dryRun?: boolean
+}
+/** Options for `Repository.cherrypick` and `Repository.revert`. */
+export interface CherrypickOptions {
+  /** The parent (starting from 1) to compute the changes against, required for merge commits. */
+  mainline?: number
+  mergeOptions?: MergeOptions
+  checkoutOptions?: CheckoutOptions
+}
+/** The fields of a commit most often needed when listing history. */
+export interface CommitSummary {
+  oid: Oid
+  parents: Array<Oid>
+  author: Signature
+  /** The committer time. */
+  time: Time
+  /** The first paragraph of the message. */
+  summary: string
+}
+export const enum DescribeStrategy {
+  /** Only annotated tags are considered. */
+  Default = 0,
+  /** Lightweight tags are considered too. */
+  Tags = 1,
+  /** Any reference is considered, e.g. branches and remote tracking branches. */
+  All = 2
+}
+/** Options for `Repository.describe` and `Commit.describe`. */
+export interface DescribeOptions {
+  strategy?: DescribeStrategy
+  /** Only consider the tags matching the given glob pattern (e.g. `v*`). */
+  pattern?: string
+  /** The number of hex digits of the abbreviated commit id. Defaults to 7. */
+  abbreviatedSize?: number
+  /** Always output the long format, even when the commit matches a tag. */
+  alwaysUseLongFormat?: boolean
+  /** Appended when the working directory has local changes. Ignored by `Commit.describe`. */
+  dirtySuffix?: string
+  /** Only follow the first parent of merge commits when searching. */
+  onlyFollowFirstParent?: boolean
+  /** Output the abbreviated commit id instead of failing when no reference is found. */
+  showCommitOidAsFallback?: boolean
+}
+/** Options for the `Repository.diff*` methods. */
+export interface DiffOptions {
+  pathspecs?: Array<string>
+  contextLines?: number
+  interhunkLines?: number
+  includeUntracked?: boolean
+  includeIgnored?: boolean
+  recurseUntrackedDirs?: boolean
+  ignoreWhitespace?: boolean
+  ignoreWhitespaceChange?: boolean
+  ignoreWhitespaceEol?: boolean
+  reverse?: boolean
+  /** Detect renamed files once the diff has been computed. */
+  findRenames?: boolean
+}
+export const enum Delta {
+  Unmodified = 0,
+  Added = 1,
+  Deleted = 2,
+  Modified = 3,
+  Renamed = 4,
+  Copied = 5,
+  Ignored = 6,
+  Untracked = 7,
+  Typechange = 8,
+  Unreadable = 9,
+  Conflicted = 10
+}
+export const enum FileMode {
+  Unreadable = 0,
+  Tree = 16384,
+  Blob = 33188,
+  BlobGroupWritable = 33204,
+  BlobExecutable = 33261,
+  Link = 40960,
+  Commit = 57344
+}
+export interface DiffFile {
+  path?: string
+  oid: Oid
+  mode: FileMode
+  size: number
+}
+export interface DiffDelta {
+  status: Delta
+  oldFile: DiffFile
+  newFile: DiffFile
+  binary: boolean
+}
+export interface DiffLine {
+  /** The line origin, as printed in patches: ` `, `+`, `-`, or one of the EOF newline markers. */
+  origin: string
+  oldLineno?: number
+  newLineno?: number
+  content: string
+}
+export interface DiffHunk {
+  header: string
+  oldStart: number
+  oldLines: number
+  newStart: number
+  newLines: number
+  lines: Array<DiffLine>
+}
+export const enum DiffFormat {
+  /** Full `git diff` output. */
+  Patch = 0,
+  /** Only the file headers of the patch. */
+  PatchHeader = 1,
+  /** Like `git diff --raw`. */
+  Raw = 2,
+  /** Like `git diff --name-only`. */
+  NameOnly = 3,
+  /** Like `git diff --name-status`. */
+  NameStatus = 4
+}
+export interface DiffStats {
+  filesChanged: number
+  insertions: number
+  deletions: number
+}
+export const enum ApplyLocation {
+  Workdir = 0,
+  Index = 1,
+  Both = 2
+}
+/** Options for `Repository.apply`. */
+export interface ApplyOptions {
+  /** Only check whether the diff applies, without writing anything. */
+  check?: boolean
+}
 export interface FetchOptions {
   remote?: string
   prune?: boolean
   credentialsCallback?: (url: string, username?: string) => Credentials
   skipCertificateCheck?: boolean
+  transferProgress?: (progress: TransferProgress) => void
+  sidebandProgress?: (message: string) => void
+  signal?: AbortSignal
+}
+/** Options for `Remote.download`. */
+export interface DownloadOptions {
+  credentialsCallback?: (url: string, username?: string) => Credentials
+  transferProgress?: (progress: TransferProgress) => void
+  sidebandProgress?: (message: string) => void
+  signal?: AbortSignal
+}
+export interface IndexEntry {
+  path: string
+  oid: Oid
+  mode: FileMode
+}
+/** The entries of a conflicted path. A missing side means the file does not exist there. */
+export interface IndexConflict {
+  ancestor?: IndexEntry
+  our?: IndexEntry
+  their?: IndexEntry
+}
+/** Options for `Repository.log`, mirroring the ones of `git log`. */
+export interface LogOptions {
+  /** The revisions to walk, e.g. `main`, `v1.0..HEAD`, `main...feature` or `^v1.0`. Defaults to HEAD. */
+  revisions?: Array<string>
+  /** Only include the commits changing files matching the pathspecs. */
+  paths?: Array<string>
+  /** Only include the commits committed at or after the given time, in seconds since epoch. */
+  since?: number
+  /** Only include the commits committed at or before the given time, in seconds since epoch. */
+  until?: number
+  /** A regular expression matched against the author, formatted as `Name <email>`. */
+  author?: string
+  /** A regular expression matched against the committer, formatted as `Name <email>`. */
+  committer?: string
+  /** A regular expression matched against the commit message. */
+  grep?: string
+  /** Match the author, committer and grep patterns case-insensitively. */
+  ignoreCase?: boolean
+  /** The number of matching commits to skip before returning results. */
+  skip?: number
+  /** The maximum number of commits to return. */
+  maxCount?: number
+  /** Only include the commits with more than one parent. */
+  merges?: boolean
+  /** Only include the commits with at most one parent. */
+  noMerges?: boolean
+  /** Follow only the first parent of merge commits. */
+  firstParent?: boolean
+}
+/** How to resolve conflicting hunks while merging files. */
+export const enum FileFavor {
+  /** Record the conflict in the index. */
+  Normal = 0,
+  Ours = 1,
+  Theirs = 2,
+  /** Include both sides, one after the other. */
+  Union = 3
+}
+export interface MergeOptions {
+  /** Detect renames, defaults to true. */
+  findRenames?: boolean
+  /** Similarity (0-100) to consider a file renamed, defaults to 50. */
+  renameThreshold?: number
+  /** Stop at the first conflict instead of recording it in the index. */
+  failOnConflict?: boolean
+  fileFavor?: FileFavor
+  /** Write conflict markers including the common ancestor. */
+  diff3Style?: boolean
+  ignoreWhitespace?: boolean
+  ignoreWhitespaceChange?: boolean
+  ignoreWhitespaceEol?: boolean
+}
+/** The user's `merge.ff` preference. */
+export const enum MergePreference {
+  None = 0,
+  NoFastForward = 1,
+  FastForwardOnly = 2
+}
+export interface MergeAnalysis {
+  /** All the given commits are already reachable from HEAD. */
+  upToDate: boolean
+  /** HEAD can be fast-forwarded to the given commit. */
+  fastForward: boolean
+  /** The commits can be merged with a merge commit. Also set when a fast-forward is possible. */
+  normal: boolean
+  /** HEAD points to a branch without commits yet. */
+  unborn: boolean
+  preference: MergePreference
+}
+export const enum ObjectType {
+  Any = 0,
+  Commit = 1,
+  Tree = 2,
+  Blob = 3,
+  Tag = 4
+}
+export interface TransferProgress {
+  totalObjects: number
+  indexedObjects: number
+  receivedObjects: number
+  localObjects: number
+  totalDeltas: number
+  indexedDeltas: number
+  receivedBytes: number
+}
+export interface PushTransferProgress {
+  current: number
+  total: number
+  bytes: number
+}
+export const enum PackBuilderStage {
+  AddingObjects = 0,
+  Deltafication = 1
+}
+export interface PackProgress {
+  stage: PackBuilderStage
+  current: number
+  total: number
+}
+/** Options for `Remote.push`. */
+export interface PushOptions {
+  credentialsCallback?: (url: string, username?: string) => Credentials
+  pushTransferProgress?: (progress: PushTransferProgress) => void
+  packProgress?: (progress: PackProgress) => void
+  signal?: AbortSignal
+}
+export const enum RebaseOperationType {
+  Pick = 0,
+  Reword = 1,
+  Edit = 2,
+  Squash = 3,
+  Fixup = 4,
+  Exec = 5
+}
+export interface RebaseOperation {
+  /** The position of the operation in the rebase. */
+  index: number
+  kind: RebaseOperationType
+  /** The commit being applied. */
+  id: Oid
+  exec?: string
+}
+/** An operation of an in-memory rebase which does not apply cleanly. */
+export interface RebaseConflict {
+  operation: RebaseOperation
+  paths: Array<string>
+}
+export interface InMemoryRebaseResult {
+  /** The commits created for the operations applied so far. */
+  commits: Array<Oid>
+  /** The new tip of the rebased branch, set only if all the operations applied cleanly. */
+  head?: Oid
+  /**
+  * The name of the reference moved to the new tip. This is null if the rebase stopped on
+  * a conflict, or if the rebased branch was not given by a reference (e.g. an annotated
+  * commit looked up by id, or a detached HEAD): in that case no reference is updated and
+  * the caller is responsible for pointing one at `head`.
+  */
+  updatedRef?: string
+  /** The first operation which could not be applied, stopping the rebase. */
+  conflict?: RebaseConflict
+}
+/** Options for `Repository.rebase` and `Repository.openRebase`. */
+export interface RebaseOptions {
+  /** The branch to rebase. Defaults to HEAD. */
+  branch?: AnnotatedCommit
+  /** The commit the branch has been forked from. Defaults to rebasing all the reachable commits. */
+  upstream?: AnnotatedCommit
+  /** The commit to rebase onto. Defaults to upstream. */
+  onto?: AnnotatedCommit
+  /** Do not write rebase information for other git tools to inspect. */
+  quiet?: boolean
+  /**
+  * Rebase without touching the index and the working directory.
+  * Results and conflicts are only available through `Rebase.index`.
+  */
+  inmemory?: boolean
+  mergeOptions?: MergeOptions
+  checkoutOptions?: CheckoutOptions
+}
+export const enum RebasePlanAction {
+  Pick = 0,
+  /** Pick the commit, replacing its message with the one of the step. */
+  Reword = 1,
+  /** Pick the commit, then pause to allow amending it. */
+  Edit = 2,
+  /** Meld the commit into the previous one, concatenating the messages. */
+  Squash = 3,
+  /** Meld the commit into the previous one, discarding its message. */
+  Fixup = 4,
+  Drop = 5
+}
+export const enum RebasePlanStatus {
+  /** The plan has not been started yet. */
+  Pending = 0,
+  /** Paused after committing an edit step. */
+  Edit = 1,
+  /** Paused on a step which does not apply cleanly: conflicts have to be resolved in the index. */
+  Conflict = 2,
+  Done = 3
+}
+export interface RebasePlanStep {
+  action: RebasePlanAction
+  /** The id of the commit, as an hex string. */
+  id: string
+  summary: string
+  /** The message to use for reword and squash steps. Defaults to the original one. */
+  message?: string
+}
+/**
+ * An interactive rebase todo list, executed with `Repository.runRebasePlan`.
+ * Commit ids are hex strings, so that the plan can be serialized and resumed later.
+ */
+export interface RebasePlan {
+  /** The branch updated once all the steps have been executed. Null for a detached HEAD. */
+  branch?: string
+  origHead: string
+  onto: string
+  todo: Array<RebasePlanStep>
+  /** The step the plan is paused on, already removed from the todo list. */
+  current?: RebasePlanStep
+  status: RebasePlanStatus
+}
+export interface RebasePlanOptions {
+  /** The commit to rebase onto. Defaults to upstream. */
+  onto?: string
+  /** The branch to rebase. Defaults to the current one. */
+  branch?: string
+  /** Move `fixup!` and `squash!` commits right after the commit they refer to. */
+  autosquash?: boolean
 }
 export const enum ReferenceType {
   Direct = 1,
   Symbolic = 2
 }
+/** The outcome of the update of a single remote reference. */
+export interface PushResult {
+  refName: string
+  accepted: boolean
+  /** The rejection message sent by the server, if any. */
+  message?: string
+}
+/** The result of `Repository.revparse`. */
+export interface Revspec {
+  /** The left side of a range, or the only object of a single revision. */
+  from?: object
+  /** The right side of a range. */
+  to?: object
+  /** The spec is a range, e.g. `main..feature`. */
+  range: boolean
+  /** The spec is a symmetric difference, e.g. `main...feature`. */
+  mergeBase: boolean
+}
+/** The result of `Repository.revparseExt`. */
+export interface RevparseExt {
+  object: object
+  /** The reference the spec was resolved through, e.g. `refs/heads/main` for `main~2`. */
+  reference?: Reference
+}
+export interface RevwalkIteratorResult {
+  done: boolean
+  value?: Oid | CommitSummary
+}
+export const enum SequencerAction {
+  Pick = 0,
+  Revert = 1
+}
+export interface SequencerStep {
+  action: SequencerAction
+  id: Oid
+  summary: string
+}
+export interface StashFlags {
+  /** Leave the changes already added to the index in place. */
+  keepIndex?: boolean
+  /** Stash the untracked files too, removing them from the working directory. */
+  includeUntracked?: boolean
+  /** Stash the ignored files too, removing them from the working directory. */
+  includeIgnored?: boolean
+}
+export interface StashEntry {
+  /** The position in the stash list, 0 being the most recent. */
+  index: number
+  message: string
+  oid: Oid
+}
+export const enum StashApplyProgress {
+  None = 0,
+  LoadingStash = 1,
+  AnalyzeIndex = 2,
+  AnalyzeModified = 3,
+  AnalyzeUntracked = 4,
+  CheckoutUntracked = 5,
+  CheckoutModified = 6,
+  Done = 7
+}
+export interface StashApplyOptions {
+  /** Restore the changes added to the index too, not only the ones of the working directory. */
+  reinstateIndex?: boolean
+  checkoutOptions?: CheckoutOptions
+  progress?: (progress: StashApplyProgress) => void
+}
+/** Status bits of a file, to be tested against `StatusEntry.status`. */
+export const enum FileStatus {
+  Current = 0,
+  IndexNew = 1,
+  IndexModified = 2,
+  IndexDeleted = 4,
+  IndexRenamed = 8,
+  IndexTypechange = 16,
+  WtNew = 128,
+  WtModified = 256,
+  WtDeleted = 512,
+  WtTypechange = 1024,
+  WtRenamed = 2048,
+  WtUnreadable = 4096,
+  Ignored = 16384,
+  Conflicted = 32768
+}
+/** Options for `Repository.statuses`. Defaults match `git status`. */
+export interface StatusOptions {
+  includeUntracked?: boolean
+  includeIgnored?: boolean
+  recurseUntrackedDirs?: boolean
+  pathspecs?: Array<string>
+  renamesHeadToIndex?: boolean
+  renamesIndexToWorkdir?: boolean
+}
+export interface StatusRename {
+  oldPath?: string
+  newPath?: string
+}
+export interface StatusEntry {
+  path: string
+  /** Combination of `FileStatus` bits. */
+  status: number
+  headToIndexRename?: StatusRename
+  indexToWorkdirRename?: StatusRename
+}
 export const enum RepositoryState {
   Clean = 0,
   Merge = 1,
@@ -42,6 +581,29 @@ export const enum Sort {
   Time = 2,
   Reverse = 3
 }
+/**
+ * A commit together with the information on how it has been looked up,
+ * as used by merge and rebase operations.
+ */
+export class AnnotatedCommit {
+  id(): Oid
+  /** The name of the reference the commit has been looked up from, if any. */
+  refname(): string | null
+}
+export class Branch {
+  /** The short name of the branch, e.g. `main` or `origin/main`. */
+  name(): Promise<string | null>
+  /** Whether HEAD points to this branch. */
+  isHead(): Promise<boolean>
+  /** Renames the branch. If force is true, an existing branch with the same name is overwritten. */
+  rename(newName: string, force: boolean): Promise<void>
+  /** Deletes the branch. Fails if HEAD points to it. */
+  delete(): Promise<void>
+  /** The branch tracked by this one, or null if no upstream is configured. */
+  upstream(): Promise<Branch | null>
+  /** Sets the upstream of the branch, e.g. `origin/main`. Null removes the upstream configuration. */
+  setUpstream(name?: string | undefined | null): Promise<void>
+}
 export class Commit {
   asObject(): object
   oid(): Oid
@@ -49,6 +611,11 @@ export class Commit {
   author(): Signature
   committer(): Signature
   getParents(): Array<Commit>
+  /**
+  * Describes the commit like `git describe <commit>`, naming it after the closest reachable tag
+  * of the given repository.
+  */
+  describe(repository: Repository, options?: DescribeOptions | undefined | null): Promise<string>
   getTree(): Tree
 }
 export class Time {
@@ -71,9 +638,32 @@ export class Credentials {
   static usernameAndPassword(username: string, password: string): Credentials
   static sshKeyFromMemory(username: string, publicKey: string | undefined | null, privateKey: string, passphrase?: string | undefined | null): Credentials
 }
+export class Diff {
+  /** Parses a unified diff, as produced by `git diff` or `Diff.toPatch`. */
+  static fromBuffer(buffer: string | Buffer): Diff
+  /** Formats the diff as text. Defaults to the full patch. */
+  toPatch(format?: DiffFormat | undefined | null): Promise<string>
+  deltas(): Promise<Array<DiffDelta>>
+  /** Returns the hunks of the delta at the given index. Binary files have no hunks. */
+  hunks(deltaIndex: number): Promise<Array<DiffHunk>>
+  stats(): Promise<DiffStats>
+}
 export class Index {
   addPath(path: string): Promise<void>
+  /** Removes the path from the index, resolving its conflict if any. */
+  removePath(path: string): Promise<void>
+  /** Adds an entry pointing to an existing blob, resolving the conflict on its path if any. */
+  addEntry(path: string, oid: Oid, mode?: FileMode | undefined | null): Promise<void>
+  hasConflicts(): Promise<boolean>
+  conflicts(): Promise<Array<IndexConflict>>
+  /** Writes the index back to disk. */
+  write(): Promise<void>
   writeTree(): Promise<Oid>
+  /**
+  * Writes the index as a tree into the given repository.
+  * Needed for in-memory indexes, which are not backed by a repository.
+  */
+  writeTreeTo(repository: Repository): Promise<Oid>
 }
 export class Oid {
   static fromString(val: string): Oid
@@ -82,8 +672,36 @@ export class Oid {
 }
 export class Object {
   toString(): string
+  oid(): Oid
+  kind(): ObjectType | null
   asTree(): Tree | null
   asCommit(): Commit | null
+  asTag(): Tag | null
+}
+/**
+ * A rebase in progress. Operations are applied one at a time with `next`,
+ * then committed with `commit` once any conflict in the index has been resolved.
+ */
+export class Rebase {
+  operations(): Promise<Array<RebaseOperation>>
+  /** The operation being applied, or null if `next` has not been called yet. */
+  currentOperation(): Promise<RebaseOperation | null>
+  /**
+  * Applies the next operation, leaving any conflict in the index.
+  * Resolves to null when all the operations have been applied.
+  */
+  next(): Promise<RebaseOperation | null>
+  /**
+  * Commits the current operation. Author and message default to the ones of the original commit.
+  * Resolves to null if the changes have already been applied upstream.
+  */
+  commit(committer: Signature, author?: Signature | undefined | null, message?: string | undefined | null): Promise<Oid | null>
+  /** Aborts the rebase, resetting the repository and the working directory to their original state. */
+  abort(): Promise<void>
+  /** Finishes a rebase once all the operations have been applied and committed. */
+  finish(signature?: Signature | undefined | null): Promise<void>
+  /** The index holding the result of the current operation, including its conflicts. */
+  index(): Promise<Index>
 }
 export class Reference {
   toString(): string
@@ -92,11 +710,11 @@ export class Reference {
   name(): string | null
 }
 export class Remote {
-  connect(direction: Direction, credentialsCallback?: (url: string, username?: string) => Credentials): Promise<void>
+  connect(direction: Direction, credentialsCallback?: (url: string, username?: string) => Credentials, signal?: AbortSignal): Promise<void>
   disconnect(): Promise<void>
   referenceList(): Promise<Array<RemoteHead>>
-  push(refSpecs: Array<string>, credentialsCallback?: (url: string, username?: string) => Credentials): Promise<void>
-  download(refSpecs: Array<string>, credentialsCallback?: (url: string, username?: string) => Credentials): Promise<void>
+  push(refSpecs: Array<string>, options?: PushOptions | undefined | null): Promise<Array<PushResult>>
+  download(refSpecs: Array<string>, options?: DownloadOptions | undefined | null): Promise<void>
 }
 export class RemoteHead {
   name(): string
@@ -113,15 +731,162 @@ export class Repository {
   isEmpty(): Promise<boolean>
   path(): string
   checkout(refName: string): Promise<void>
+  /**
+  * Rebases HEAD onto the given reference, committing every operation as is.
+  * The rebase is aborted if any of the commits does not apply cleanly.
+  */
   fastRebase(ontoRef: string): Promise<void>
   state(): Promise<RepositoryState>
   findCommit(target: Oid): Promise<Commit>
+  findTag(target: Oid): Promise<Tag>
   findTree(target: Oid): Promise<Tree>
+  findAnnotatedCommit(target: Oid): Promise<AnnotatedCommit>
+  /**
+  * Creates an annotated commit from a reference, keeping track of the reference name
+  * for merge and rebase messages.
+  */
+  referenceToAnnotatedCommit(reference: Reference): Promise<AnnotatedCommit>
   createRemote(name: string, url: string): Promise<Remote>
   findRemote(name: string): Promise<Remote>
+  /** Lists the branches of the given type, defaulting to all of them. */
+  branches(branchType?: BranchType | undefined | null): Promise<Array<Branch>>
+  /** Computes, for every local branch, its upstream and how many commits it is ahead or behind. */
+  branchTrackingSummary(): Promise<Array<BranchTracking>>
+  /** Counts the commits unique to local and to upstream. */
+  graphAheadBehind(local: Oid, upstream: Oid): Promise<AheadBehind>
+  /** Finds the best common ancestor of two commits. */
+  mergeBase(one: Oid, two: Oid): Promise<Oid>
+  /** Finds all the best common ancestors of two commits, e.g. after criss-cross merges. */
+  mergeBases(one: Oid, two: Oid): Promise<Array<Oid>>
+  /** Finds the best common ancestor of the first commit and a hypothetical merge of all the others. */
+  mergeBaseMany(oids: Array<Oid>): Promise<Oid>
+  /** Finds the common ancestor of all the commits, as for an octopus merge. */
+  mergeBaseOctopus(oids: Array<Oid>): Promise<Oid>
+  /** Whether commit is a descendant of ancestor. A commit is not considered a descendant of itself. */
+  graphDescendantOf(commit: Oid, ancestor: Oid): Promise<boolean>
+  /** Whether commit is reachable from any of the descendants, or is one of them. */
+  graphReachableFromAny(commit: Oid, descendants: Array<Oid>): Promise<boolean>
   createBranch(name: string, commit: Commit | Oid | string, force: boolean): object
   getBranchCommit(name: string | Reference): Promise<Commit>
+  /**
+  * Describes HEAD like `git describe`, naming it after the closest reachable tag (e.g. `v1.0.0-2-gabc1234`).
+  * The dirty suffix is appended when the working directory has local changes.
+  */
+  describe(options?: DescribeOptions | undefined | null): Promise<string>
   index(): Promise<Index>
+  statuses(options?: StatusOptions | undefined | null): Promise<Array<StatusEntry>>
+  /** Diffs two trees. A missing tree is treated as empty. */
+  diffTreeToTree(oldTree?: Tree | undefined | null, newTree?: Tree | undefined | null, options?: DiffOptions | undefined | null): Promise<Diff>
+  /** Diffs a tree against an index, defaulting to the repository index. */
+  diffTreeToIndex(tree?: Tree | undefined | null, index?: Index | undefined | null, options?: DiffOptions | undefined | null): Promise<Diff>
+  /** Diffs an index against the working directory, defaulting to the repository index. */
+  diffIndexToWorkdir(index?: Index | undefined | null, options?: DiffOptions | undefined | null): Promise<Diff>
+  /**
+  * Diffs a tree against the working directory, using the index to account for staged changes
+  * like `git diff <tree>` does.
+  */
+  diffTreeToWorkdirWithIndex(tree?: Tree | undefined | null, options?: DiffOptions | undefined | null): Promise<Diff>
+  /**
+  * Applies a diff to the working directory and/or the index.
+  * Resolves to the paths the diff does not apply to: if there are any, nothing is written.
+  */
+  apply(diff: Diff, location: ApplyLocation, options?: ApplyOptions | undefined | null): Promise<Array<string>>
+  /** Applies a diff to a tree, returning the resulting index without touching the repository. */
+  applyToTree(tree: Tree, diff: Diff): Promise<Index>
+  /** Analyzes how the given commits could be merged into HEAD. */
+  mergeAnalysis(theirHeads: Array<AnnotatedCommit>): Promise<MergeAnalysis>
+  /**
+  * Merges the given commits into HEAD, updating the index and the working directory.
+  * Conflicts are left in the index and the repository is left in the `Merge` state:
+  * the merge commit has to be created by the caller.
+  */
+  merge(theirHeads: Array<AnnotatedCommit>, mergeOptions?: MergeOptions | undefined | null, checkoutOptions?: CheckoutOptions | undefined | null): Promise<void>
+  /** Merges two commits in memory, returning the resulting index. */
+  mergeCommits(ours: Commit, theirs: Commit, options?: MergeOptions | undefined | null): Promise<Index>
+  /** Merges two trees in memory given their common ancestor, returning the resulting index. */
+  mergeTrees(ancestor: Tree, ours: Tree, theirs: Tree, options?: MergeOptions | undefined | null): Promise<Index>
+  /**
+  * Applies the changes introduced by the commit to the index and the working directory.
+  * Conflicts are left in the index and the repository is left in the `CherryPick` state.
+  */
+  cherrypick(commit: Commit, options?: CherrypickOptions | undefined | null): Promise<void>
+  /**
+  * Cherry-picks the commit onto another one in memory, returning the resulting index.
+  * The mainline parent (starting from 1) is required when cherry-picking a merge commit.
+  */
+  cherrypickCommit(commit: Commit, onto: Commit, mainline?: number | undefined | null, options?: MergeOptions | undefined | null): Promise<Index>
+  /**
+  * Reverts the changes introduced by the commit in the index and the working directory.
+  * Conflicts are left in the index and the repository is left in the `Revert` state.
+  */
+  revert(commit: Commit, options?: CherrypickOptions | undefined | null): Promise<void>
+  /**
+  * Reverts the commit on top of another one in memory, returning the resulting index.
+  * The mainline parent (starting from 1) is required when reverting a merge commit.
+  */
+  revertCommit(commit: Commit, onto: Commit, mainline?: number | undefined | null, options?: MergeOptions | undefined | null): Promise<Index>
+  /**
+  * Cherry-picks the commits in the given range (e.g. `main..feature`), oldest first.
+  * The sequence pauses on the first conflict, leaving the repository in the `CherryPickSequence` state.
+  */
+  cherrypickSequence(range: string, options?: CherrypickOptions | undefined | null): Promise<Sequencer>
+  /**
+  * Reverts the commits in the given range (e.g. `v1.0..v1.1`), newest first.
+  * The sequence pauses on the first conflict, leaving the repository in the `RevertSequence` state.
+  */
+  revertSequence(range: string, options?: CherrypickOptions | undefined | null): Promise<Sequencer>
+  /** Resumes an interrupted cherry-pick or revert sequence from its on-disk state. */
+  openSequencer(options?: CherrypickOptions | undefined | null): Promise<Sequencer>
+  /** Starts rebasing a branch. The operations are applied one at a time through the returned `Rebase`. */
+  rebase(options?: RebaseOptions | undefined | null): Promise<Rebase>
+  /** Reopens an interrupted rebase. Branch, upstream and onto options are ignored. */
+  openRebase(options?: RebaseOptions | undefined | null): Promise<Rebase>
+  /**
+  * Rebases a branch without touching the index and the working directory, so it works on bare repositories too.
+  * The branch reference is updated only if all the operations apply cleanly, otherwise
+  * the rebase is aborted and the first conflicting operation is reported.
+  * Commits are created by the given committer, defaulting to the repository signature.
+  */
+  rebaseInMemory(options: RebaseOptions, committer?: Signature | undefined | null): Promise<InMemoryRebaseResult>
+  /**
+  * Builds an interactive rebase plan picking the commits of the branch not reachable from upstream.
+  * The todo list can be edited before running it with `runRebasePlan`.
+  */
+  rebasePlan(upstream: string, options?: RebasePlanOptions | undefined | null): Promise<RebasePlan>
+  /**
+  * Executes a rebase plan in the working directory, starting or resuming it.
+  * Resolves to the updated plan, paused on an edit or a conflicting step, or done.
+  */
+  runRebasePlan(plan: RebasePlan): Promise<RebasePlan>
+  /** Aborts a paused rebase plan, restoring the original branch. */
+  abortRebasePlan(plan: RebasePlan): Promise<RebasePlan>
+  /**
+  * Creates an annotated tag object pointing to target and the `refs/tags/<name>` reference.
+  * If force is true, an existing tag with the same name is overwritten.
+  * Resolves to the id of the tag object.
+  */
+  createTag(name: string, target: Oid, tagger: Signature, message: string, force: boolean): Promise<Oid>
+  /**
+  * Creates a `refs/tags/<name>` reference pointing directly to target.
+  * If force is true, an existing tag with the same name is overwritten.
+  */
+  createLightweightTag(name: string, target: Oid, force: boolean): Promise<Oid>
+  /** Lists the tag names, optionally filtered by a glob pattern (e.g. `v1.*`). */
+  tagNames(pattern?: string | undefined | null): Promise<Array<string>>
+  /** Deletes the `refs/tags/<name>` reference. The tag object is left in the object database. */
+  deleteTag(name: string): Promise<void>
+  /** Saves the local changes to a new stash, resetting the working directory to HEAD. */
+  stashSave(stasher: Signature, message?: string | undefined | null, flags?: StashFlags | undefined | null): Promise<Oid>
+  /** Lists the stashed states, the most recent first. */
+  stashList(): Promise<Array<StashEntry>>
+  /** Applies a stashed state to the working directory, keeping it in the stash list. */
+  stashApply(index: number, options?: StashApplyOptions | undefined | null): Promise<void>
+  /** Applies a stashed state to the working directory, removing it from the stash list if successful. */
+  stashPop(index: number, options?: StashApplyOptions | undefined | null): Promise<void>
+  /** Removes a stashed state from the stash list. */
+  stashDrop(index: number): Promise<void>
+  /** Computes the blame of a file, as a list of hunks sorted by line. */
+  blame(path: string, options?: BlameOptions | undefined | null): Promise<Array<BlameHunk>>
   signature(): Promise<Signature>
   createCommit(updateRef: string | undefined | null, author: Signature, committer: Signature, message: string, tree: Tree, parents: Array<Commit>): Promise<Oid>
   fetch(options?: FetchOptions | undefined | null): Promise<void>
@@ -129,9 +894,18 @@ export class Repository {
   head(): Promise<Reference>
   config(): Promise<Config>
   reset(target: Commit | Reference | Oid, resetType?: ResetType | undefined | null): Promise<void>
+  /** Parses a revision spec like `HEAD~3`, `main..feature` or `main...feature`. */
+  revparse(spec: string): Promise<Revspec>
+  /** Finds the object of a single revision spec, e.g. `HEAD~3`, `main@{upstream}`, `v1.2^{tree}` or a short id. */
+  revparseSingle(spec: string): Promise<object>
+  /** Finds the object of a single revision spec and the reference it was resolved through, if any. */
+  revparseExt(spec: string): Promise<RevparseExt>
   getReference(reference: string): Promise<Reference>
   getReferenceNames(referenceType?: ReferenceType | undefined | null): Promise<Array<string>>
-  createRevWalk(): Promise<Revwalk>
+  /** Lists the commits matching the options, newest first, like `git log`. */
+  log(options?: LogOptions | undefined | null): Promise<Array<CommitSummary>>
+  /** Creates a revwalk, which is also iterable with `for await` over the walked oids. */
+  createRevWalk(): Promise<Revwalk & AsyncIterable<Oid>>
 }
 export class InitOptions {
   constructor()
@@ -141,10 +915,77 @@ export class InitOptions {
 export class Revwalk {
   push(oid: Oid): object
   pushRange(range: string): object
+  /** Marks a commit and its ancestors as uninteresting: they will not be yielded by the walk. */
+  hide(oid: Oid): object
+  /** Pushes the references matching the glob, e.g. `tags/*`. Globs without a `refs/` prefix are relative to `refs/`. */
+  pushGlob(glob: string): Promise<void>
+  /** Hides the references matching the glob, e.g. `remotes/origin/*`. */
+  hideGlob(glob: string): Promise<void>
+  /** Pushes the commit pointed to by the reference with the given full name, e.g. `refs/heads/main`. */
+  pushRef(reference: string): Promise<void>
+  /** Hides the commit pointed to by the reference with the given full name. */
+  hideRef(reference: string): Promise<void>
+  pushHead(): Promise<void>
+  /** Follows only the first parent of merge commits, like `git log --first-parent`. */
+  simplifyFirstParent(): Promise<void>
+  /**
+  * Only yields the commits changing files matching the pathspecs, like `git log -- <path>...`.
+  * Merge commits are yielded only if they differ from all their parents (from the first one
+  * when following first parents only). An empty list disables the filter.
+  */
+  setPathspecs(pathspecs: Array<string>): Promise<void>
   next(): Promise<Oid | null>
+  /** Returns up to count oids at once. An empty array means the walk is over. */
+  nextBatch(count: number): Promise<Array<Oid>>
+  /** Returns the summaries of up to count commits at once. An empty array means the walk is over. */
+  nextSummaryBatch(count: number): Promise<Array<CommitSummary>>
+  /**
+  * An async iterable over the oids of the walked commits, for `for await` loops.
+  * Also used when walking with `for await (const oid of revwalk)`.
+  */
+  oids(): AsyncIterableIterator<Oid>
+  /** An async iterable over the summaries of the walked commits, for `for await` loops. */
+  summaries(): AsyncIterableIterator<CommitSummary>
   reset(): Promise<void>
   sort(sorts: Array<Sort>): Promise<void>
 }
+/** An async iterator over the commits of a revwalk, fetching them in batches. */
+export class RevwalkIterator {
+  next(): Promise<RevwalkIteratorResult>
+}
+/**
+ * Cherry-picks or reverts a range of commits one at a time, pausing on conflicts.
+ * The state is stored on disk in the format used by git, so an interrupted sequence
+ * can be resumed with `Repository.openSequencer` or by git itself.
+ */
+export class Sequencer {
+  /** The commits still to be applied, starting from the one the sequence is paused on. */
+  todo(): Promise<Array<SequencerStep>>
+  /** The step the sequence is paused on, or null once all the commits have been applied. */
+  current(): Promise<SequencerStep | null>
+  /**
+  * Commits the resolved changes of the current step and applies the remaining ones.
+  * Resolves to the step the sequence paused on, or null once all the commits have been applied.
+  */
+  continue(): Promise<SequencerStep | null>
+  /** Discards the changes of the current step and applies the remaining ones. */
+  skip(): Promise<SequencerStep | null>
+  /** Stops the sequence, resetting HEAD, the index and the working directory to their original state. */
+  abort(): Promise<void>
+}
+/** An annotated tag object. */
+export class Tag {
+  oid(): Oid
+  name(): string | null
+  message(): string | null
+  /** The tagger of the tag, or null if the tag object has no tagger. */
+  tagger(): Signature | null
+  /** The id of the object the tag points to. */
+  targetId(): Oid
+  targetType(): ObjectType | null
+  /** Recursively peels the tag until a non-tag object is found. */
+  peel(): object
+}
 export class Tree {
   oid(): Oid
   entryByPath(path: string): TreeEntry
@@ -252,16 +252,33 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { Commit, Time, Signature, Config, Credentials, Index, Oid, Object, ReferenceType, Reference, Remote, RemoteHead, Repository, InitOptions, Revwalk, Tree, TreeEntry, RepositoryState, ResetType, Direction, Sort } = nativeBinding
+const { AnnotatedCommit, BranchType, Branch, Commit, Time, Signature, Config, Credentials, DescribeStrategy, Delta, FileMode, DiffFormat, ApplyLocation, Diff, Index, FileFavor, MergePreference, Oid, ObjectType, Object, PackBuilderStage, RebaseOperationType, Rebase, RebasePlanAction, RebasePlanStatus, ReferenceType, Reference, Remote, RemoteHead, Repository, InitOptions, Revwalk, RevwalkIterator, SequencerAction, Sequencer, StashApplyProgress, FileStatus, Tag, Tree, TreeEntry, RepositoryState, ResetType, Direction, Sort } = nativeBinding
 
+module.exports.AnnotatedCommit = AnnotatedCommit
+module.exports.BranchType = BranchType
+module.exports.Branch = Branch
 module.exports.Commit = Commit
 module.exports.Time = Time
 module.exports.Signature = Signature
 module.exports.Config = Config
 module.exports.Credentials = Credentials
+module.exports.DescribeStrategy = DescribeStrategy
+module.exports.Delta = Delta
+module.exports.FileMode = FileMode
+module.exports.DiffFormat = DiffFormat
+module.exports.ApplyLocation = ApplyLocation
+module.exports.Diff = Diff
 module.exports.Index = Index
+module.exports.FileFavor = FileFavor
+module.exports.MergePreference = MergePreference
 module.exports.Oid = Oid
+module.exports.ObjectType = ObjectType
 module.exports.Object = Object
+module.exports.PackBuilderStage = PackBuilderStage
+module.exports.RebaseOperationType = RebaseOperationType
+module.exports.Rebase = Rebase
+module.exports.RebasePlanAction = RebasePlanAction
+module.exports.RebasePlanStatus = RebasePlanStatus
 module.exports.ReferenceType = ReferenceType
 module.exports.Reference = Reference
 module.exports.Remote = Remote
@@ -269,6 +286,12 @@ module.exports.RemoteHead = RemoteHead
 module.exports.Repository = Repository
 module.exports.InitOptions = InitOptions
 module.exports.Revwalk = Revwalk
+module.exports.RevwalkIterator = RevwalkIterator
+module.exports.SequencerAction = SequencerAction
+module.exports.Sequencer = Sequencer
+module.exports.StashApplyProgress = StashApplyProgress
+module.exports.FileStatus = FileStatus
+module.exports.Tag = Tag
 module.exports.Tree = Tree
 module.exports.TreeEntry = TreeEntry
 module.exports.RepositoryState = RepositoryState
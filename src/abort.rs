@@ -0,0 +1,109 @@
+use napi::{Env, JsFunction, JsObject, Ref, Status};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The abort listener registered on a signal, removed once the task settles.
+struct AbortListener {
+  signal: Ref<()>,
+  listener: Ref<()>,
+}
+
+/// Cancellation state of a task, driven by an `AbortSignal`.
+#[derive(Clone, Default)]
+pub struct AbortFlag {
+  aborted: Arc<AtomicBool>,
+  listener: Arc<Mutex<Option<AbortListener>>>,
+}
+
+impl AbortFlag {
+  /// Links the flag to an `AbortSignal` (or any object exposing `aborted` and `addEventListener`).
+  pub(crate) fn from_signal(env: &Env, signal: JsObject) -> napi::Result<Self> {
+    let flag = Self::default();
+    if signal.get_named_property::<bool>("aborted")? {
+      flag.abort();
+      return Ok(flag);
+    }
+
+    let listener = {
+      let aborted = flag.aborted.clone();
+      env.create_function_from_closure("abort", move |_| {
+        aborted.store(true, Ordering::Relaxed);
+        Ok(())
+      })?
+    };
+
+    let mut listener_opts = env.create_object()?;
+    listener_opts.set_named_property("once", true)?;
+    let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+
+    let registered = AbortListener {
+      signal: env.create_reference(signal)?,
+      listener: env.create_reference(listener)?,
+    };
+
+    let signal: JsObject = env.get_reference_value(&registered.signal)?;
+    let listener: JsFunction = env.get_reference_value(&registered.listener)?;
+    *flag.listener.lock().unwrap() = Some(registered);
+
+    let added = add_event_listener.call(
+      Some(&signal),
+      &[
+        env.create_string("abort")?.into_unknown(),
+        listener.into_unknown(),
+        listener_opts.into_unknown(),
+      ],
+    );
+
+    if let Err(e) = added {
+      flag.release(*env)?;
+      return Err(e);
+    }
+
+    Ok(flag)
+  }
+
+  /// Removes the listener from the signal. Must be called on the main thread once the task settles.
+  pub(crate) fn release(&self, env: Env) -> napi::Result<()> {
+    let Some(mut registered) = self.listener.lock().unwrap().take() else {
+      return Ok(());
+    };
+
+    let signal: JsObject = env.get_reference_value(&registered.signal)?;
+    let listener: JsFunction = env.get_reference_value(&registered.listener)?;
+    registered.signal.unref(env)?;
+    registered.listener.unref(env)?;
+
+    let remove_event_listener: JsFunction = signal.get_named_property("removeEventListener")?;
+    remove_event_listener.call(
+      Some(&signal),
+      &[
+        env.create_string("abort")?.into_unknown(),
+        listener.into_unknown(),
+      ],
+    )?;
+
+    Ok(())
+  }
+
+  pub(crate) fn abort(&self) {
+    self.aborted.store(true, Ordering::Relaxed);
+  }
+
+  pub(crate) fn is_aborted(&self) -> bool {
+    self.aborted.load(Ordering::Relaxed)
+  }
+
+  /// Fails with an aborted error if the signal has been triggered.
+  pub(crate) fn check(&self) -> napi::Result<()> {
+    if self.is_aborted() {
+      Err(aborted_error())
+    } else {
+      Ok(())
+    }
+  }
+}
+
+/// The error returned by aborted tasks. Exposed to JS with the `Cancelled` code.
+pub(crate) fn aborted_error() -> napi::Error {
+  napi::Error::new(Status::Cancelled, "The operation was aborted")
+}
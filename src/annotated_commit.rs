@@ -0,0 +1,45 @@
+use crate::object::Oid;
+
+/// A commit together with the information on how it has been looked up,
+/// as used by merge and rebase operations.
+#[napi]
+pub struct AnnotatedCommit {
+  inner: git2::AnnotatedCommit<'static>,
+  refname: Option<String>,
+}
+
+unsafe impl Send for AnnotatedCommit {}
+
+impl AnnotatedCommit {
+  /// Wraps an annotated commit, together with the name of the reference it has been
+  /// looked up from. The name is kept here since `git2::AnnotatedCommit::refname`
+  /// panics on commits not looked up from a reference.
+  pub(crate) fn new(value: git2::AnnotatedCommit<'_>, refname: Option<String>) -> Self {
+    Self {
+      inner: unsafe { std::mem::transmute(value) },
+      refname,
+    }
+  }
+
+  pub(crate) fn inner(&self) -> &git2::AnnotatedCommit<'_> {
+    &self.inner
+  }
+
+  pub(crate) fn refname_str(&self) -> Option<&str> {
+    self.refname.as_deref()
+  }
+}
+
+#[napi]
+impl AnnotatedCommit {
+  #[napi]
+  pub fn id(&self) -> Oid {
+    Oid(self.inner.id())
+  }
+
+  /// The name of the reference the commit has been looked up from, if any.
+  #[napi]
+  pub fn refname(&self) -> Option<String> {
+    self.refname.clone()
+  }
+}
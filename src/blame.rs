@@ -0,0 +1,79 @@
+use crate::commit::Signature;
+use crate::object::Oid;
+use napi::bindgen_prelude::*;
+
+/// Options for `Repository.blame`.
+#[napi(object, object_to_js = false)]
+#[derive(Default)]
+pub struct BlameOptions {
+  /// The most recent commit to consider. Defaults to HEAD.
+  pub newest_commit: Option<ClassInstance<Oid>>,
+  /// The oldest commit to consider. Defaults to the first commit without a parent.
+  pub oldest_commit: Option<ClassInstance<Oid>>,
+  /// The first line in the file to blame (1-based).
+  pub min_line: Option<u32>,
+  /// The last line in the file to blame (1-based, inclusive).
+  pub max_line: Option<u32>,
+  /// Follow only the first parent of merge commits.
+  pub first_parent: Option<bool>,
+  pub ignore_whitespace: Option<bool>,
+}
+
+impl From<BlameOptions> for git2::BlameOptions {
+  fn from(value: BlameOptions) -> Self {
+    let mut opts = git2::BlameOptions::new();
+    opts
+      .first_parent(value.first_parent.unwrap_or(false))
+      .ignore_whitespace(value.ignore_whitespace.unwrap_or(false));
+
+    if let Some(newest_commit) = value.newest_commit {
+      opts.newest_commit(newest_commit.0);
+    }
+
+    if let Some(oldest_commit) = value.oldest_commit {
+      opts.oldest_commit(oldest_commit.0);
+    }
+
+    if let Some(min_line) = value.min_line {
+      opts.min_line(min_line as usize);
+    }
+
+    if let Some(max_line) = value.max_line {
+      opts.max_line(max_line as usize);
+    }
+
+    opts
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct BlameHunk {
+  /// The commit where the lines were last changed.
+  pub final_commit_id: Oid,
+  pub final_start_line: u32,
+  pub final_signature: Signature,
+  /// The commit where the lines were found, before following renames and copies.
+  pub orig_commit_id: Oid,
+  pub orig_path: Option<String>,
+  pub orig_start_line: u32,
+  pub orig_signature: Signature,
+  pub lines_in_hunk: u32,
+  /// Whether the hunk has been tracked to the boundary commit (the oldest commit).
+  pub boundary: bool,
+}
+
+impl From<git2::BlameHunk<'_>> for BlameHunk {
+  fn from(value: git2::BlameHunk<'_>) -> Self {
+    Self {
+      final_commit_id: Oid(value.final_commit_id()),
+      final_start_line: value.final_start_line() as u32,
+      final_signature: value.final_signature().into(),
+      orig_commit_id: Oid(value.orig_commit_id()),
+      orig_path: value.path().map(|p| p.to_string_lossy().to_string()),
+      orig_start_line: value.orig_start_line() as u32,
+      orig_signature: value.orig_signature().into(),
+      lines_in_hunk: value.lines_in_hunk() as u32,
+      boundary: value.is_boundary(),
+    }
+  }
+}
@@ -0,0 +1,168 @@
+use crate::repository::Repository;
+use napi::bindgen_prelude::*;
+use napi::tokio::sync::Mutex;
+use napi::JsObject;
+
+#[napi]
+pub enum BranchType {
+  Local,
+  Remote,
+  All,
+}
+
+impl From<BranchType> for Option<git2::BranchType> {
+  fn from(value: BranchType) -> Self {
+    match value {
+      BranchType::Local => Some(git2::BranchType::Local),
+      BranchType::Remote => Some(git2::BranchType::Remote),
+      BranchType::All => None,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct AheadBehind {
+  /// The number of commits reachable from local only.
+  pub ahead: u32,
+  /// The number of commits reachable from upstream only.
+  pub behind: u32,
+}
+
+impl From<(usize, usize)> for AheadBehind {
+  fn from((ahead, behind): (usize, usize)) -> Self {
+    Self {
+      ahead: ahead as u32,
+      behind: behind as u32,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct BranchTracking {
+  /// The short name of the local branch.
+  pub name: String,
+  /// The full name of the upstream reference, or null if the branch has no upstream.
+  pub upstream: Option<String>,
+  /// The upstream is configured, but its reference does not exist.
+  pub gone: bool,
+  pub ahead: u32,
+  pub behind: u32,
+}
+
+/// A git2 branch which can be moved to the JS main thread to be wrapped.
+pub struct BranchHandle(git2::Branch<'static>);
+
+unsafe impl Send for BranchHandle {}
+
+impl BranchHandle {
+  pub(crate) fn new(branch: git2::Branch<'_>) -> Self {
+    Self(unsafe { std::mem::transmute(branch) })
+  }
+}
+
+#[napi]
+pub struct Branch {
+  inner: Mutex<git2::Branch<'static>>,
+  repository: Reference<Repository>,
+}
+
+unsafe impl Send for Branch {}
+unsafe impl Sync for Branch {}
+
+impl Branch {
+  pub(crate) fn new(handle: BranchHandle, repository: Reference<Repository>) -> Self {
+    Self {
+      inner: Mutex::new(handle.0),
+      repository,
+    }
+  }
+}
+
+#[napi]
+impl Branch {
+  /// The short name of the branch, e.g. `main` or `origin/main`.
+  #[napi]
+  pub async fn name(&self) -> Result<Option<String>> {
+    let _repository = self.repository.repository.lock().await;
+    let inner = self.inner.lock().await;
+    Ok(
+      inner
+        .name()
+        .map_err(anyhow::Error::from)?
+        .map(ToString::to_string),
+    )
+  }
+
+  /// Whether HEAD points to this branch.
+  #[napi]
+  pub async fn is_head(&self) -> bool {
+    let _repository = self.repository.repository.lock().await;
+    let inner = self.inner.lock().await;
+    inner.is_head()
+  }
+
+  /// Renames the branch. If force is true, an existing branch with the same name is overwritten.
+  #[napi]
+  pub async fn rename(&self, new_name: String, force: bool) -> Result<()> {
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    let renamed = inner
+      .rename(&new_name, force)
+      .map_err(anyhow::Error::from)?;
+
+    *inner = unsafe { std::mem::transmute(renamed) };
+    Ok(())
+  }
+
+  /// Deletes the branch. Fails if HEAD points to it.
+  #[napi]
+  pub async fn delete(&self) -> Result<()> {
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    inner.delete().map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// The branch tracked by this one, or null if no upstream is configured.
+  #[napi(ts_return_type = "Promise<Branch | null>")]
+  pub fn upstream(&self, this: Reference<Branch>, env: Env) -> Result<JsObject> {
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_upstream().await {
+        Ok(upstream) => deferred.resolve(move |env| {
+          upstream
+            .map(|u| Ok(Branch::new(u, this.repository.clone(env)?)))
+            .transpose()
+        }),
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
+  }
+
+  /// Sets the upstream of the branch, e.g. `origin/main`. Null removes the upstream configuration.
+  #[napi]
+  pub async fn set_upstream(&self, name: Option<String>) -> Result<()> {
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    inner
+      .set_upstream(name.as_deref())
+      .map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+}
+
+impl Branch {
+  async fn internal_upstream(&self) -> anyhow::Result<Option<BranchHandle>> {
+    let _repository = self.repository.repository.lock().await;
+    let inner = self.inner.lock().await;
+    match inner.upstream() {
+      Ok(upstream) => Ok(Some(BranchHandle::new(upstream))),
+      Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+      Err(e) => Err(e.into()),
+    }
+  }
+}
@@ -0,0 +1,51 @@
+use git2::build::CheckoutBuilder;
+
+/// Options for operations updating the working directory. Defaults to a safe checkout.
+#[napi(object)]
+#[derive(Default)]
+pub struct CheckoutOptions {
+  /// Take any action necessary to get the working directory to match the target,
+  /// overwriting local changes.
+  pub force: Option<bool>,
+  /// Proceed with the checkout even if there are conflicts, writing the files that can be updated.
+  pub allow_conflicts: Option<bool>,
+  pub remove_untracked: Option<bool>,
+  pub remove_ignored: Option<bool>,
+  /// Use the "ours" side of conflicted files.
+  pub use_ours: Option<bool>,
+  /// Use the "theirs" side of conflicted files.
+  pub use_theirs: Option<bool>,
+  /// Write conflict markers including the common ancestor (`merge.conflictStyle=diff3`).
+  pub conflict_style_diff3: Option<bool>,
+  /// Only check out the given paths.
+  pub paths: Option<Vec<String>>,
+  /// Report what would be done, without touching the working directory.
+  pub dry_run: Option<bool>,
+}
+
+impl CheckoutOptions {
+  pub(crate) fn to_builder(&self) -> CheckoutBuilder<'static> {
+    let mut builder = CheckoutBuilder::new();
+    if self.force.unwrap_or(false) {
+      builder.force();
+    }
+
+    if self.dry_run.unwrap_or(false) {
+      builder.dry_run();
+    }
+
+    builder
+      .allow_conflicts(self.allow_conflicts.unwrap_or(false))
+      .remove_untracked(self.remove_untracked.unwrap_or(false))
+      .remove_ignored(self.remove_ignored.unwrap_or(false))
+      .use_ours(self.use_ours.unwrap_or(false))
+      .use_theirs(self.use_theirs.unwrap_or(false))
+      .conflict_style_diff3(self.conflict_style_diff3.unwrap_or(false));
+
+    for path in self.paths.iter().flatten() {
+      builder.path(path);
+    }
+
+    builder
+  }
+}
@@ -0,0 +1,46 @@
+use crate::checkout::CheckoutOptions;
+use crate::merge::MergeOptions;
+
+/// Options for `Repository.cherrypick` and `Repository.revert`.
+#[napi(object)]
+#[derive(Default)]
+pub struct CherrypickOptions {
+  /// The parent (starting from 1) to compute the changes against, required for merge commits.
+  pub mainline: Option<u32>,
+  pub merge_options: Option<MergeOptions>,
+  pub checkout_options: Option<CheckoutOptions>,
+}
+
+impl From<&CherrypickOptions> for git2::CherrypickOptions<'static> {
+  fn from(value: &CherrypickOptions) -> Self {
+    let mut opts = git2::CherrypickOptions::new();
+    opts.mainline(value.mainline.unwrap_or(0));
+
+    if let Some(merge_options) = &value.merge_options {
+      opts.merge_opts(merge_options.into());
+    }
+
+    if let Some(checkout_options) = &value.checkout_options {
+      opts.checkout_builder(checkout_options.to_builder());
+    }
+
+    opts
+  }
+}
+
+impl From<&CherrypickOptions> for git2::RevertOptions<'static> {
+  fn from(value: &CherrypickOptions) -> Self {
+    let mut opts = git2::RevertOptions::new();
+    opts.mainline(value.mainline.unwrap_or(0));
+
+    if let Some(merge_options) = &value.merge_options {
+      opts.merge_opts(merge_options.into());
+    }
+
+    if let Some(checkout_options) = &value.checkout_options {
+      opts.checkout_builder(checkout_options.to_builder());
+    }
+
+    opts
+  }
+}
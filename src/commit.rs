@@ -1,4 +1,7 @@
+use crate::describe::DescribeOptions;
 use crate::object::{Object, Oid};
+use crate::repository::Repository;
+use crate::task::DescribeCommit;
 use crate::tree::Tree;
 use napi::bindgen_prelude::*;
 
@@ -48,6 +51,21 @@ impl Commit {
     self.inner.parents().map(|p| p.into()).collect()
   }
 
+  /// Describes the commit like `git describe <commit>`, naming it after the closest reachable tag
+  /// of the given repository.
+  #[napi(ts_return_type = "Promise<string>")]
+  pub fn describe(
+    &self,
+    repository: Reference<Repository>,
+    options: Option<DescribeOptions>,
+  ) -> AsyncTask<DescribeCommit> {
+    AsyncTask::new(DescribeCommit::new(
+      repository,
+      self.inner.id(),
+      options.unwrap_or_default(),
+    ))
+  }
+
   #[napi]
   pub fn get_tree(&self) -> Result<Tree> {
     Ok(
@@ -66,6 +84,33 @@ impl From<Commit> for git2::Commit<'_> {
   }
 }
 
+/// The fields of a commit most often needed when listing history.
+#[napi(object, object_from_js = false)]
+pub struct CommitSummary {
+  pub oid: Oid,
+  pub parents: Vec<Oid>,
+  pub author: Signature,
+  /// The committer time.
+  pub time: Time,
+  /// The first paragraph of the message.
+  pub summary: String,
+}
+
+impl From<&git2::Commit<'_>> for CommitSummary {
+  fn from(value: &git2::Commit) -> Self {
+    Self {
+      oid: Oid(value.id()),
+      parents: value.parent_ids().map(Oid).collect(),
+      author: value.author().into(),
+      time: Time {
+        time: value.time().seconds(),
+        offset: value.time().offset_minutes(),
+      },
+      summary: value.summary().unwrap_or_default().to_string(),
+    }
+  }
+}
+
 #[napi]
 #[derive(Copy, Clone)]
 pub struct Time {
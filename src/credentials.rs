@@ -1,5 +1,11 @@
+use crate::js_callback::JsCallback;
 use anyhow::Result;
 use git2::Cred;
+use napi::bindgen_prelude::{ClassInstance, FromNapiValue};
+use napi::threadsafe_function::ThreadSafeCallContext;
+use napi::{Env, JsFunction, JsUnknown};
+
+pub(crate) type CredentialsCallback = JsCallback<(String, Option<String>)>;
 
 #[derive(Clone)]
 enum CredentialType {
@@ -54,3 +60,42 @@ impl Credentials {
     })
   }
 }
+
+pub(crate) fn create_credentials_callback(
+  env: &Env,
+  callback: JsFunction,
+) -> napi::Result<CredentialsCallback> {
+  JsCallback::new(
+    env,
+    callback,
+    |ctx: ThreadSafeCallContext<(String, Option<String>)>| {
+      let (url, username) = ctx.value;
+      Ok(vec![
+        ctx.env.create_string(&url)?.into_unknown(),
+        if let Some(username) = username {
+          ctx.env.create_string(&username)?.into_unknown()
+        } else {
+          ctx.env.get_undefined()?.into_unknown()
+        },
+      ])
+    },
+  )
+}
+
+/// Asks the JS callback for credentials, blocking the calling thread until it answers.
+pub(crate) fn request_credentials(
+  callback: &CredentialsCallback,
+  url: &str,
+  username: Option<&str>,
+) -> std::result::Result<Cred, git2::Error> {
+  callback
+    .call(
+      (url.to_string(), username.map(ToString::to_string)),
+      |value: JsUnknown| {
+        let credentials: ClassInstance<Credentials> = ClassInstance::from_unknown(value)?;
+        Ok(credentials.as_ref().clone())
+      },
+    )
+    .and_then(|credentials| credentials.to_cred())
+    .map_err(|e| git2::Error::from_str(&e.to_string()))
+}
@@ -0,0 +1,65 @@
+#[napi]
+pub enum DescribeStrategy {
+  /// Only annotated tags are considered.
+  Default,
+  /// Lightweight tags are considered too.
+  Tags,
+  /// Any reference is considered, e.g. branches and remote tracking branches.
+  All,
+}
+
+/// Options for `Repository.describe` and `Commit.describe`.
+#[napi(object)]
+#[derive(Default)]
+pub struct DescribeOptions {
+  pub strategy: Option<DescribeStrategy>,
+  /// Only consider the tags matching the given glob pattern (e.g. `v*`).
+  pub pattern: Option<String>,
+  /// The number of hex digits of the abbreviated commit id. Defaults to 7.
+  pub abbreviated_size: Option<u32>,
+  /// Always output the long format, even when the commit matches a tag.
+  pub always_use_long_format: Option<bool>,
+  /// Appended when the working directory has local changes. Ignored by `Commit.describe`.
+  pub dirty_suffix: Option<String>,
+  /// Only follow the first parent of merge commits when searching.
+  pub only_follow_first_parent: Option<bool>,
+  /// Output the abbreviated commit id instead of failing when no reference is found.
+  pub show_commit_oid_as_fallback: Option<bool>,
+}
+
+impl From<&DescribeOptions> for git2::DescribeOptions {
+  fn from(value: &DescribeOptions) -> Self {
+    let mut opts = git2::DescribeOptions::new();
+    match value.strategy {
+      Some(DescribeStrategy::Tags) => opts.describe_tags(),
+      Some(DescribeStrategy::All) => opts.describe_all(),
+      _ => &mut opts,
+    };
+
+    if let Some(pattern) = &value.pattern {
+      opts.pattern(pattern);
+    }
+
+    opts
+      .only_follow_first_parent(value.only_follow_first_parent.unwrap_or(false))
+      .show_commit_oid_as_fallback(value.show_commit_oid_as_fallback.unwrap_or(false));
+
+    opts
+  }
+}
+
+impl From<&DescribeOptions> for git2::DescribeFormatOptions {
+  fn from(value: &DescribeOptions) -> Self {
+    let mut opts = git2::DescribeFormatOptions::new();
+    if let Some(size) = value.abbreviated_size {
+      opts.abbreviated_size(size);
+    }
+
+    if let Some(suffix) = &value.dirty_suffix {
+      opts.dirty_suffix(suffix);
+    }
+
+    opts.always_use_long_format(value.always_use_long_format.unwrap_or(false));
+    opts
+  }
+}
@@ -0,0 +1,371 @@
+use crate::object::Oid;
+use crate::repository::Repository;
+use napi::bindgen_prelude::*;
+use napi::tokio::sync::{Mutex, MutexGuard};
+
+/// Options for the `Repository.diff*` methods.
+#[napi(object)]
+#[derive(Default)]
+pub struct DiffOptions {
+  pub pathspecs: Option<Vec<String>>,
+  pub context_lines: Option<u32>,
+  pub interhunk_lines: Option<u32>,
+  pub include_untracked: Option<bool>,
+  pub include_ignored: Option<bool>,
+  pub recurse_untracked_dirs: Option<bool>,
+  pub ignore_whitespace: Option<bool>,
+  pub ignore_whitespace_change: Option<bool>,
+  pub ignore_whitespace_eol: Option<bool>,
+  pub reverse: Option<bool>,
+  /// Detect renamed files once the diff has been computed.
+  pub find_renames: Option<bool>,
+}
+
+impl From<&DiffOptions> for git2::DiffOptions {
+  fn from(value: &DiffOptions) -> Self {
+    let mut opts = git2::DiffOptions::new();
+    opts
+      .include_untracked(value.include_untracked.unwrap_or(false))
+      .include_ignored(value.include_ignored.unwrap_or(false))
+      .recurse_untracked_dirs(value.recurse_untracked_dirs.unwrap_or(false))
+      .ignore_whitespace(value.ignore_whitespace.unwrap_or(false))
+      .ignore_whitespace_change(value.ignore_whitespace_change.unwrap_or(false))
+      .ignore_whitespace_eol(value.ignore_whitespace_eol.unwrap_or(false))
+      .reverse(value.reverse.unwrap_or(false));
+
+    if let Some(context_lines) = value.context_lines {
+      opts.context_lines(context_lines);
+    }
+
+    if let Some(interhunk_lines) = value.interhunk_lines {
+      opts.interhunk_lines(interhunk_lines);
+    }
+
+    for pathspec in value.pathspecs.iter().flatten() {
+      opts.pathspec(pathspec);
+    }
+
+    opts
+  }
+}
+
+#[napi]
+pub enum Delta {
+  Unmodified,
+  Added,
+  Deleted,
+  Modified,
+  Renamed,
+  Copied,
+  Ignored,
+  Untracked,
+  Typechange,
+  Unreadable,
+  Conflicted,
+}
+
+impl From<git2::Delta> for Delta {
+  fn from(value: git2::Delta) -> Self {
+    match value {
+      git2::Delta::Unmodified => Delta::Unmodified,
+      git2::Delta::Added => Delta::Added,
+      git2::Delta::Deleted => Delta::Deleted,
+      git2::Delta::Modified => Delta::Modified,
+      git2::Delta::Renamed => Delta::Renamed,
+      git2::Delta::Copied => Delta::Copied,
+      git2::Delta::Ignored => Delta::Ignored,
+      git2::Delta::Untracked => Delta::Untracked,
+      git2::Delta::Typechange => Delta::Typechange,
+      git2::Delta::Unreadable => Delta::Unreadable,
+      git2::Delta::Conflicted => Delta::Conflicted,
+    }
+  }
+}
+
+#[napi]
+pub enum FileMode {
+  Unreadable = 0,
+  Tree = 16384,
+  Blob = 33188,
+  BlobGroupWritable = 33204,
+  BlobExecutable = 33261,
+  Link = 40960,
+  Commit = 57344,
+}
+
+impl From<git2::FileMode> for FileMode {
+  fn from(value: git2::FileMode) -> Self {
+    match value {
+      git2::FileMode::Unreadable => FileMode::Unreadable,
+      git2::FileMode::Tree => FileMode::Tree,
+      git2::FileMode::Blob => FileMode::Blob,
+      git2::FileMode::BlobGroupWritable => FileMode::BlobGroupWritable,
+      git2::FileMode::BlobExecutable => FileMode::BlobExecutable,
+      git2::FileMode::Link => FileMode::Link,
+      git2::FileMode::Commit => FileMode::Commit,
+    }
+  }
+}
+
+impl From<u32> for FileMode {
+  fn from(value: u32) -> Self {
+    match value {
+      16384 => FileMode::Tree,
+      33188 => FileMode::Blob,
+      33204 => FileMode::BlobGroupWritable,
+      33261 => FileMode::BlobExecutable,
+      40960 => FileMode::Link,
+      57344 => FileMode::Commit,
+      _ => FileMode::Unreadable,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct DiffFile {
+  pub path: Option<String>,
+  pub oid: Oid,
+  pub mode: FileMode,
+  pub size: i64,
+}
+
+impl From<git2::DiffFile<'_>> for DiffFile {
+  fn from(value: git2::DiffFile<'_>) -> Self {
+    Self {
+      path: value.path().map(|p| p.to_string_lossy().to_string()),
+      oid: Oid(value.id()),
+      mode: value.mode().into(),
+      size: value.size() as i64,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct DiffDelta {
+  pub status: Delta,
+  pub old_file: DiffFile,
+  pub new_file: DiffFile,
+  pub binary: bool,
+}
+
+impl From<git2::DiffDelta<'_>> for DiffDelta {
+  fn from(value: git2::DiffDelta<'_>) -> Self {
+    Self {
+      status: value.status().into(),
+      binary: value.flags().is_binary(),
+      old_file: value.old_file().into(),
+      new_file: value.new_file().into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct DiffLine {
+  /// The line origin, as printed in patches: ` `, `+`, `-`, or one of the EOF newline markers.
+  pub origin: String,
+  pub old_lineno: Option<u32>,
+  pub new_lineno: Option<u32>,
+  pub content: String,
+}
+
+impl From<git2::DiffLine<'_>> for DiffLine {
+  fn from(value: git2::DiffLine<'_>) -> Self {
+    Self {
+      origin: value.origin().to_string(),
+      old_lineno: value.old_lineno(),
+      new_lineno: value.new_lineno(),
+      content: String::from_utf8_lossy(value.content()).to_string(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct DiffHunk {
+  pub header: String,
+  pub old_start: u32,
+  pub old_lines: u32,
+  pub new_start: u32,
+  pub new_lines: u32,
+  pub lines: Vec<DiffLine>,
+}
+
+#[napi]
+pub enum DiffFormat {
+  /// Full `git diff` output.
+  Patch,
+  /// Only the file headers of the patch.
+  PatchHeader,
+  /// Like `git diff --raw`.
+  Raw,
+  /// Like `git diff --name-only`.
+  NameOnly,
+  /// Like `git diff --name-status`.
+  NameStatus,
+}
+
+impl From<DiffFormat> for git2::DiffFormat {
+  fn from(value: DiffFormat) -> Self {
+    match value {
+      DiffFormat::Patch => git2::DiffFormat::Patch,
+      DiffFormat::PatchHeader => git2::DiffFormat::PatchHeader,
+      DiffFormat::Raw => git2::DiffFormat::Raw,
+      DiffFormat::NameOnly => git2::DiffFormat::NameOnly,
+      DiffFormat::NameStatus => git2::DiffFormat::NameStatus,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct DiffStats {
+  pub files_changed: u32,
+  pub insertions: u32,
+  pub deletions: u32,
+}
+
+#[napi]
+pub enum ApplyLocation {
+  Workdir,
+  Index,
+  Both,
+}
+
+impl From<ApplyLocation> for git2::ApplyLocation {
+  fn from(value: ApplyLocation) -> Self {
+    match value {
+      ApplyLocation::Workdir => git2::ApplyLocation::WorkDir,
+      ApplyLocation::Index => git2::ApplyLocation::Index,
+      ApplyLocation::Both => git2::ApplyLocation::Both,
+    }
+  }
+}
+
+/// Options for `Repository.apply`.
+#[napi(object)]
+#[derive(Default)]
+pub struct ApplyOptions {
+  /// Only check whether the diff applies, without writing anything.
+  pub check: Option<bool>,
+}
+
+pub struct DiffHandle(git2::Diff<'static>);
+
+unsafe impl Send for DiffHandle {}
+
+impl DiffHandle {
+  pub(crate) fn new(diff: git2::Diff<'_>) -> Self {
+    Self(unsafe { std::mem::transmute(diff) })
+  }
+}
+
+#[napi]
+pub struct Diff {
+  pub(crate) inner: Mutex<git2::Diff<'static>>,
+  /// The repository the diff has been computed in, which blobs and files are read from.
+  /// Diffs parsed from a buffer do not belong to any repository.
+  repository: Option<Reference<Repository>>,
+}
+
+unsafe impl Send for Diff {}
+unsafe impl Sync for Diff {}
+
+impl Diff {
+  pub(crate) fn new(handle: DiffHandle, repository: Option<Reference<Repository>>) -> Self {
+    Self {
+      inner: Mutex::new(handle.0),
+      repository,
+    }
+  }
+
+  async fn lock_repository(&self) -> Option<MutexGuard<'_, git2::Repository>> {
+    match &self.repository {
+      Some(repository) => Some(repository.repository.lock().await),
+      None => None,
+    }
+  }
+}
+
+#[napi]
+impl Diff {
+  /// Parses a unified diff, as produced by `git diff` or `Diff.toPatch`.
+  #[napi]
+  pub fn from_buffer(buffer: Either<String, Buffer>) -> Result<Diff> {
+    let diff = match &buffer {
+      Either::A(text) => git2::Diff::from_buffer(text.as_bytes()),
+      Either::B(buffer) => git2::Diff::from_buffer(buffer.as_ref()),
+    };
+
+    let diff = diff.map_err(anyhow::Error::from)?;
+    Ok(Diff::new(DiffHandle::new(diff), None))
+  }
+
+  /// Formats the diff as text. Defaults to the full patch.
+  #[napi]
+  pub async fn to_patch(&self, format: Option<DiffFormat>) -> Result<String> {
+    let _repository = self.lock_repository().await;
+    let inner = self.inner.lock().await;
+    let mut patch = Vec::new();
+    inner
+      .print(format.unwrap_or(DiffFormat::Patch).into(), |_, _, line| {
+        if matches!(line.origin(), '+' | '-' | ' ') {
+          patch.push(line.origin() as u8);
+        }
+        patch.extend_from_slice(line.content());
+        true
+      })
+      .map_err(anyhow::Error::from)?;
+
+    Ok(String::from_utf8_lossy(&patch).to_string())
+  }
+
+  #[napi]
+  pub async fn deltas(&self) -> Vec<DiffDelta> {
+    let _repository = self.lock_repository().await;
+    let inner = self.inner.lock().await;
+    inner.deltas().map(DiffDelta::from).collect()
+  }
+
+  /// Returns the hunks of the delta at the given index. Binary files have no hunks.
+  #[napi]
+  pub async fn hunks(&self, delta_index: u32) -> Result<Vec<DiffHunk>> {
+    let _repository = self.lock_repository().await;
+    let inner = self.inner.lock().await;
+    let Some(patch) =
+      git2::Patch::from_diff(&inner, delta_index as usize).map_err(anyhow::Error::from)?
+    else {
+      return Ok(vec![]);
+    };
+
+    let mut hunks = Vec::with_capacity(patch.num_hunks());
+    for hunk_idx in 0..patch.num_hunks() {
+      let (hunk, line_count) = patch.hunk(hunk_idx).map_err(anyhow::Error::from)?;
+      let lines = (0..line_count)
+        .map(|line_idx| patch.line_in_hunk(hunk_idx, line_idx).map(DiffLine::from))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(anyhow::Error::from)?;
+
+      hunks.push(DiffHunk {
+        header: String::from_utf8_lossy(hunk.header()).to_string(),
+        old_start: hunk.old_start(),
+        old_lines: hunk.old_lines(),
+        new_start: hunk.new_start(),
+        new_lines: hunk.new_lines(),
+        lines,
+      });
+    }
+
+    Ok(hunks)
+  }
+
+  #[napi]
+  pub async fn stats(&self) -> Result<DiffStats> {
+    let _repository = self.lock_repository().await;
+    let inner = self.inner.lock().await;
+    let stats = inner.stats().map_err(anyhow::Error::from)?;
+
+    Ok(DiffStats {
+      files_changed: stats.files_changed() as u32,
+      insertions: stats.insertions() as u32,
+      deletions: stats.deletions() as u32,
+    })
+  }
+}
@@ -1,7 +1,9 @@
-use crate::credentials::Credentials;
+use crate::abort::AbortFlag;
+use crate::credentials::create_credentials_callback;
+use crate::progress::{create_sideband_progress_callback, create_transfer_progress_callback};
+use crate::remote::{Remote, RemoteCallbacksOpts};
 use anyhow::Result;
-use napi::bindgen_prelude::{ClassInstance, FromNapiValue};
-use napi::{Env, JsFunction, JsUnknown, Ref};
+use napi::{Env, JsFunction, JsObject};
 
 #[napi(object)]
 #[derive(Default)]
@@ -11,6 +13,12 @@ pub struct FetchOptions {
   #[napi(ts_type = "(url: string, username?: string) => Credentials")]
   pub credentials_callback: Option<JsFunction>,
   pub skip_certificate_check: Option<bool>,
+  #[napi(ts_type = "(progress: TransferProgress) => void")]
+  pub transfer_progress: Option<JsFunction>,
+  #[napi(ts_type = "(message: string) => void")]
+  pub sideband_progress: Option<JsFunction>,
+  #[napi(ts_type = "AbortSignal")]
+  pub signal: Option<JsObject>,
 }
 
 impl FetchOptions {
@@ -18,9 +26,26 @@ impl FetchOptions {
     let ret = FetchOpts {
       remote: self.remote,
       prune: self.prune,
-      credentials_callback: self
-        .credentials_callback
-        .and_then(|cred_cb| env.create_reference(cred_cb).ok()),
+      callbacks: RemoteCallbacksOpts {
+        credentials: self
+          .credentials_callback
+          .map(|cred_cb| create_credentials_callback(env, cred_cb))
+          .transpose()?,
+        transfer_progress: self
+          .transfer_progress
+          .map(|f| create_transfer_progress_callback(env, f))
+          .transpose()?,
+        sideband_progress: self
+          .sideband_progress
+          .map(|f| create_sideband_progress_callback(env, f))
+          .transpose()?,
+        abort: self
+          .signal
+          .map(|s| AbortFlag::from_signal(env, s))
+          .transpose()?
+          .unwrap_or_default(),
+        ..Default::default()
+      },
       skip_certificate_check: self.skip_certificate_check,
     };
 
@@ -28,41 +53,57 @@ impl FetchOptions {
   }
 }
 
+/// Options for `Remote.download`.
+#[napi(object)]
+#[derive(Default)]
+pub struct DownloadOptions {
+  #[napi(ts_type = "(url: string, username?: string) => Credentials")]
+  pub credentials_callback: Option<JsFunction>,
+  #[napi(ts_type = "(progress: TransferProgress) => void")]
+  pub transfer_progress: Option<JsFunction>,
+  #[napi(ts_type = "(message: string) => void")]
+  pub sideband_progress: Option<JsFunction>,
+  #[napi(ts_type = "AbortSignal")]
+  pub signal: Option<JsObject>,
+}
+
+impl DownloadOptions {
+  pub fn into_callbacks(self, env: &Env) -> Result<RemoteCallbacksOpts> {
+    Ok(RemoteCallbacksOpts {
+      credentials: self
+        .credentials_callback
+        .map(|cred_cb| create_credentials_callback(env, cred_cb))
+        .transpose()?,
+      transfer_progress: self
+        .transfer_progress
+        .map(|f| create_transfer_progress_callback(env, f))
+        .transpose()?,
+      sideband_progress: self
+        .sideband_progress
+        .map(|f| create_sideband_progress_callback(env, f))
+        .transpose()?,
+      abort: self
+        .signal
+        .map(|s| AbortFlag::from_signal(env, s))
+        .transpose()?
+        .unwrap_or_default(),
+      ..Default::default()
+    })
+  }
+}
+
 pub struct FetchOpts {
   pub remote: Option<String>,
   pub prune: Option<bool>,
-  pub credentials_callback: Option<Ref<()>>,
+  pub callbacks: RemoteCallbacksOpts,
   pub skip_certificate_check: Option<bool>,
 }
 
 impl FetchOpts {
-  pub fn to_git_fetch_opts<'a>(&'a self, env: &'a Env) -> Result<git2::FetchOptions> {
-    let mut cb = git2::RemoteCallbacks::default();
-
-    {
-      if let Some(cred_cb_ref) = self.credentials_callback.as_ref() {
-        let cred_cb: JsFunction = env.get_reference_value(cred_cb_ref)?;
-        cb.credentials(move |url, username, _| {
-          (|| -> Result<ClassInstance<Credentials>, anyhow::Error> {
-            let credentials = cred_cb.call::<JsUnknown>(
-              None,
-              &[
-                env.create_string(url)?.into_unknown(),
-                if let Some(username) = username {
-                  env.create_string(username)?.into_unknown()
-                } else {
-                  env.get_undefined()?.into_unknown()
-                },
-              ],
-            )?;
-
-            Ok(ClassInstance::from_unknown(credentials)?)
-          })()
-          .and_then(|c| c.to_cred())
-          .map_err(|err| git2::Error::from_str(&err.to_string()))
-        });
-      }
-    }
+  /// Builds the git2 fetch options. JS callbacks are invoked through threadsafe
+  /// functions, so the returned options must be used off the JS main thread.
+  pub fn to_git_fetch_opts(&self) -> git2::FetchOptions<'static> {
+    let mut cb = Remote::prepare_remote_callbacks(self.callbacks.clone());
 
     if self.skip_certificate_check.unwrap_or(false) {
       cb.certificate_check(|_cert, _domain| Ok(git2::CertificateCheckStatus::CertificateOk));
@@ -71,6 +112,6 @@ impl FetchOpts {
     let mut fo = git2::FetchOptions::default();
     fo.remote_callbacks(cb);
 
-    Ok(fo)
+    fo
   }
 }
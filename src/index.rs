@@ -1,11 +1,50 @@
+use crate::diff::FileMode;
 use crate::object::Oid;
+use crate::repository::Repository;
+use crate::task::WriteTreeTo;
 use napi::bindgen_prelude::*;
 use napi::tokio::sync::Mutex;
+use napi::JsObject;
 use std::path::Path;
 
+#[napi(object, object_from_js = false)]
+pub struct IndexEntry {
+  pub path: String,
+  pub oid: Oid,
+  pub mode: FileMode,
+}
+
+impl From<git2::IndexEntry> for IndexEntry {
+  fn from(value: git2::IndexEntry) -> Self {
+    Self {
+      path: String::from_utf8_lossy(&value.path).to_string(),
+      oid: Oid(value.id),
+      mode: value.mode.into(),
+    }
+  }
+}
+
+/// The entries of a conflicted path. A missing side means the file does not exist there.
+#[napi(object, object_from_js = false)]
+pub struct IndexConflict {
+  pub ancestor: Option<IndexEntry>,
+  pub our: Option<IndexEntry>,
+  pub their: Option<IndexEntry>,
+}
+
+impl From<git2::IndexConflict> for IndexConflict {
+  fn from(value: git2::IndexConflict) -> Self {
+    Self {
+      ancestor: value.ancestor.map(IndexEntry::from),
+      our: value.our.map(IndexEntry::from),
+      their: value.their.map(IndexEntry::from),
+    }
+  }
+}
+
 #[napi]
 pub struct Index {
-  inner: Mutex<git2::Index>,
+  pub(crate) inner: Mutex<git2::Index>,
 }
 
 impl From<git2::Index> for Index {
@@ -28,9 +67,109 @@ impl Index {
     )
   }
 
+  /// Removes the path from the index, resolving its conflict if any.
+  #[napi]
+  pub async fn remove_path(&self, path: String) -> Result<()> {
+    let mut index = self.inner.lock().await;
+    Ok(
+      index
+        .remove_path(Path::new(&path))
+        .map_err(anyhow::Error::from)?,
+    )
+  }
+
+  /// Adds an entry pointing to an existing blob, resolving the conflict on its path if any.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn add_entry(
+    &self,
+    path: String,
+    oid: ClassInstance<Oid>,
+    mode: Option<FileMode>,
+    this: Reference<Index>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oid = oid.0;
+
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_add_entry(path, oid, mode).await {
+        Ok(()) => deferred.resolve(|_| Ok(())),
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
+  }
+
+  #[napi]
+  pub async fn has_conflicts(&self) -> bool {
+    let index = self.inner.lock().await;
+    index.has_conflicts()
+  }
+
+  #[napi]
+  pub async fn conflicts(&self) -> Result<Vec<IndexConflict>> {
+    let index = self.inner.lock().await;
+    let conflicts = index
+      .conflicts()
+      .map_err(anyhow::Error::from)?
+      .map(|c| c.map(IndexConflict::from))
+      .collect::<std::result::Result<Vec<_>, _>>()
+      .map_err(anyhow::Error::from)?;
+
+    Ok(conflicts)
+  }
+
+  /// Writes the index back to disk.
+  #[napi]
+  pub async fn write(&self) -> Result<()> {
+    let mut index = self.inner.lock().await;
+    Ok(index.write().map_err(anyhow::Error::from)?)
+  }
+
   #[napi]
   pub async fn write_tree(&self) -> Result<Oid> {
     let mut index = self.inner.lock().await;
     Ok(index.write_tree().map(Oid).map_err(anyhow::Error::from)?)
   }
+
+  /// Writes the index as a tree into the given repository.
+  /// Needed for in-memory indexes, which are not backed by a repository.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn write_tree_to(
+    &self,
+    repository: Reference<Repository>,
+    this: Reference<Index>,
+  ) -> AsyncTask<WriteTreeTo> {
+    AsyncTask::new(WriteTreeTo::new(this, repository))
+  }
+}
+
+impl Index {
+  async fn internal_add_entry(
+    &self,
+    path: String,
+    oid: git2::Oid,
+    mode: Option<FileMode>,
+  ) -> anyhow::Result<()> {
+    let mut index = self.inner.lock().await;
+    index.remove_path(Path::new(&path))?;
+
+    let entry = git2::IndexEntry {
+      ctime: git2::IndexTime::new(0, 0),
+      mtime: git2::IndexTime::new(0, 0),
+      dev: 0,
+      ino: 0,
+      mode: mode.unwrap_or(FileMode::Blob) as u32,
+      uid: 0,
+      gid: 0,
+      file_size: 0,
+      id: oid,
+      flags: 0,
+      flags_extended: 0,
+      path: path.into_bytes(),
+    };
+
+    Ok(index.add(&entry)?)
+  }
 }
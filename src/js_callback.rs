@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use napi::bindgen_prelude::ToNapiValue;
+use napi::threadsafe_function::{
+  ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction, JsUnknown};
+use std::sync::{mpsc, Arc, Mutex};
+
+/// Wraps the user callback so that a thrown exception is handed back as a return value:
+/// an exception escaping a threadsafe function call would abort the whole process.
+const CATCHING_WRAPPER: &str = "(cb) => (...args) => { try { return cb(...args); } catch (e) { return e instanceof Error ? e : new Error(String(e)); } }";
+
+/// A JS function callable from any thread.
+/// Errors thrown by the function are recorded and reported on the next call.
+pub struct JsCallback<T: 'static> {
+  function: ThreadsafeFunction<T, ErrorStrategy::Fatal>,
+  error: Arc<Mutex<Option<String>>>,
+}
+
+impl<T: 'static> Clone for JsCallback<T> {
+  fn clone(&self) -> Self {
+    Self {
+      function: self.function.clone(),
+      error: self.error.clone(),
+    }
+  }
+}
+
+impl<T: 'static> JsCallback<T> {
+  pub(crate) fn new<V, F>(env: &Env, callback: JsFunction, to_args: F) -> napi::Result<Self>
+  where
+    V: ToNapiValue,
+    F: 'static + Send + FnMut(ThreadSafeCallContext<T>) -> napi::Result<Vec<V>>,
+  {
+    let wrapper = env.run_script::<_, JsFunction>(CATCHING_WRAPPER)?;
+    let wrapped = wrapper.call(None, &[callback])?;
+    let wrapped = unsafe { wrapped.cast::<JsFunction>() };
+
+    Ok(Self {
+      function: wrapped.create_threadsafe_function(0, to_args)?,
+      error: Arc::new(Mutex::new(None)),
+    })
+  }
+
+  /// The message of the first error thrown by the JS function, if any.
+  pub(crate) fn error(&self) -> Option<String> {
+    self.error.lock().unwrap().clone()
+  }
+
+  /// Queues a call without waiting for its completion.
+  /// Returns false if a previous call has thrown.
+  pub(crate) fn notify(&self, value: T) -> bool {
+    if self.error().is_some() {
+      return false;
+    }
+
+    let error = self.error.clone();
+    self.function.call_with_return_value(
+      value,
+      ThreadsafeFunctionCallMode::NonBlocking,
+      move |ret: JsUnknown| {
+        if let Ok(message) = into_error_message(ret) {
+          error.lock().unwrap().get_or_insert(message);
+        }
+
+        Ok(())
+      },
+    );
+
+    true
+  }
+
+  /// Calls the JS function and blocks until it returns, mapping its return value.
+  /// Must not be called from the JS main thread.
+  pub(crate) fn call<D, M>(&self, value: T, map: M) -> Result<D>
+  where
+    D: 'static + Send,
+    M: 'static + FnOnce(JsUnknown) -> Result<D>,
+  {
+    if let Some(message) = self.error() {
+      return Err(anyhow!(message));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    self.function.call_with_return_value(
+      value,
+      ThreadsafeFunctionCallMode::Blocking,
+      move |ret: JsUnknown| {
+        let _ = tx.send(match into_error_message(ret) {
+          Ok(message) => Err(anyhow!(message)),
+          Err(ret) => map(ret),
+        });
+
+        Ok(())
+      },
+    );
+
+    rx.recv()
+      .map_err(|_| anyhow!("callback has not been called"))?
+  }
+}
+
+/// Extracts the message if the value is an Error, otherwise gives the value back.
+fn into_error_message(value: JsUnknown) -> std::result::Result<String, JsUnknown> {
+  if !value.is_error().unwrap_or(false) {
+    return Err(value);
+  }
+
+  Ok(
+    value
+      .coerce_to_string()
+      .and_then(|s| s.into_utf8())
+      .and_then(|s| s.into_owned())
+      .unwrap_or_else(|_| "callback error".to_string()),
+  )
+}
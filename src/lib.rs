@@ -1,13 +1,33 @@
+mod abort;
+mod annotated_commit;
+mod blame;
+mod branch;
+mod checkout;
+mod cherrypick;
 mod commit;
 mod config;
 mod credentials;
+mod describe;
+mod diff;
 mod fetch_options;
 mod index;
+mod js_callback;
+mod log;
+mod merge;
 mod object;
+mod progress;
+mod push_options;
+mod rebase;
+mod rebase_plan;
 mod reference;
 mod remote;
 mod repository;
+mod revparse;
 mod revwalk;
+mod sequencer;
+mod stash;
+mod status;
+mod tag;
 mod task;
 mod tree;
 
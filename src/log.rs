@@ -0,0 +1,184 @@
+use crate::commit::CommitSummary;
+use crate::revwalk::touches_paths;
+use regex::{Regex, RegexBuilder};
+
+/// Options for `Repository.log`, mirroring the ones of `git log`.
+#[napi(object)]
+#[derive(Default)]
+pub struct LogOptions {
+  /// The revisions to walk, e.g. `main`, `v1.0..HEAD`, `main...feature` or `^v1.0`. Defaults to HEAD.
+  pub revisions: Option<Vec<String>>,
+  /// Only include the commits changing files matching the pathspecs.
+  pub paths: Option<Vec<String>>,
+  /// Only include the commits committed at or after the given time, in seconds since epoch.
+  pub since: Option<i64>,
+  /// Only include the commits committed at or before the given time, in seconds since epoch.
+  pub until: Option<i64>,
+  /// A regular expression matched against the author, formatted as `Name <email>`.
+  pub author: Option<String>,
+  /// A regular expression matched against the committer, formatted as `Name <email>`.
+  pub committer: Option<String>,
+  /// A regular expression matched against the commit message.
+  pub grep: Option<String>,
+  /// Match the author, committer and grep patterns case-insensitively.
+  pub ignore_case: Option<bool>,
+  /// The number of matching commits to skip before returning results.
+  pub skip: Option<u32>,
+  /// The maximum number of commits to return.
+  pub max_count: Option<u32>,
+  /// Only include the commits with more than one parent.
+  pub merges: Option<bool>,
+  /// Only include the commits with at most one parent.
+  pub no_merges: Option<bool>,
+  /// Follow only the first parent of merge commits.
+  pub first_parent: Option<bool>,
+}
+
+struct LogFilter {
+  since: Option<i64>,
+  until: Option<i64>,
+  author: Option<Regex>,
+  committer: Option<Regex>,
+  grep: Option<Regex>,
+  merges: bool,
+  no_merges: bool,
+}
+
+impl LogFilter {
+  fn new(options: &LogOptions) -> anyhow::Result<Self> {
+    let ignore_case = options.ignore_case.unwrap_or(false);
+    let regex = |pattern: &Option<String>| {
+      pattern
+        .as_deref()
+        .map(|p| RegexBuilder::new(p).case_insensitive(ignore_case).build())
+        .transpose()
+    };
+
+    Ok(Self {
+      since: options.since,
+      until: options.until,
+      author: regex(&options.author)?,
+      committer: regex(&options.committer)?,
+      grep: regex(&options.grep)?,
+      merges: options.merges.unwrap_or(false),
+      no_merges: options.no_merges.unwrap_or(false),
+    })
+  }
+
+  fn matches(&self, commit: &git2::Commit) -> bool {
+    let time = commit.time().seconds();
+    if self.since.is_some_and(|since| time < since) || self.until.is_some_and(|until| time > until)
+    {
+      return false;
+    }
+
+    let is_merge = commit.parent_count() > 1;
+    if (self.merges && !is_merge) || (self.no_merges && is_merge) {
+      return false;
+    }
+
+    matches_signature(&self.author, &commit.author())
+      && matches_signature(&self.committer, &commit.committer())
+      && !matches!(
+        &self.grep,
+        Some(grep) if !grep.is_match(&String::from_utf8_lossy(commit.message_bytes()))
+      )
+  }
+}
+
+fn matches_signature(pattern: &Option<Regex>, signature: &git2::Signature) -> bool {
+  let Some(pattern) = pattern else {
+    return true;
+  };
+
+  pattern.is_match(&format!(
+    "{} <{}>",
+    String::from_utf8_lossy(signature.name_bytes()),
+    String::from_utf8_lossy(signature.email_bytes())
+  ))
+}
+
+fn push_revision(
+  repository: &git2::Repository,
+  revwalk: &mut git2::Revwalk,
+  revision: &str,
+) -> anyhow::Result<()> {
+  if let Some(hidden) = revision.strip_prefix('^') {
+    revwalk.hide(repository.revparse_single(hidden)?.peel_to_commit()?.id())?;
+    return Ok(());
+  }
+
+  let revspec = repository.revparse(revision)?;
+  let from = revspec.from().map(|o| o.peel_to_commit()).transpose()?;
+  let to = revspec.to().map(|o| o.peel_to_commit()).transpose()?;
+  let mode = revspec.mode();
+
+  match (from, to) {
+    (Some(from), Some(to)) if mode.contains(git2::RevparseMode::MERGE_BASE) => {
+      // Symmetric difference: the commits reachable from either side but not from both.
+      revwalk.push(from.id())?;
+      revwalk.push(to.id())?;
+      for base in repository.merge_bases(from.id(), to.id())?.iter() {
+        revwalk.hide(*base)?;
+      }
+    }
+    (Some(from), Some(to)) => {
+      revwalk.hide(from.id())?;
+      revwalk.push(to.id())?;
+    }
+    (Some(commit), None) | (None, Some(commit)) => revwalk.push(commit.id())?,
+    (None, None) => {}
+  }
+
+  Ok(())
+}
+
+/// Walks the history from the given revisions, newest first, returning the summaries of the matching commits.
+pub(crate) fn log(
+  repository: &git2::Repository,
+  options: &LogOptions,
+) -> anyhow::Result<Vec<CommitSummary>> {
+  let filter = LogFilter::new(options)?;
+  let mut revwalk = repository.revwalk()?;
+  revwalk.set_sorting(git2::Sort::TIME)?;
+  let first_parent = options.first_parent.unwrap_or(false);
+  if first_parent {
+    revwalk.simplify_first_parent()?;
+  }
+
+  match &options.revisions {
+    Some(revisions) if !revisions.is_empty() => {
+      for revision in revisions {
+        push_revision(repository, &mut revwalk, revision)?;
+      }
+    }
+    _ => revwalk.push_head()?,
+  }
+
+  let paths = options.paths.as_deref().unwrap_or_default();
+  let mut skip = options.skip.unwrap_or(0);
+  let max_count = options.max_count.map_or(usize::MAX, |max| max as usize);
+
+  let mut summaries = vec![];
+  for oid in revwalk {
+    if summaries.len() >= max_count {
+      break;
+    }
+
+    let commit = repository.find_commit(oid?)?;
+    if !filter.matches(&commit)
+      || (!paths.is_empty() && !touches_paths(repository, &commit, paths, first_parent)?)
+    {
+      continue;
+    }
+
+    if skip > 0 {
+      skip -= 1;
+      continue;
+    }
+
+    summaries.push(CommitSummary::from(&commit));
+  }
+
+  Ok(summaries)
+}
@@ -0,0 +1,97 @@
+/// How to resolve conflicting hunks while merging files.
+#[napi]
+pub enum FileFavor {
+  /// Record the conflict in the index.
+  Normal,
+  Ours,
+  Theirs,
+  /// Include both sides, one after the other.
+  Union,
+}
+
+impl From<FileFavor> for git2::FileFavor {
+  fn from(value: FileFavor) -> Self {
+    match value {
+      FileFavor::Normal => git2::FileFavor::Normal,
+      FileFavor::Ours => git2::FileFavor::Ours,
+      FileFavor::Theirs => git2::FileFavor::Theirs,
+      FileFavor::Union => git2::FileFavor::Union,
+    }
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MergeOptions {
+  /// Detect renames, defaults to true.
+  pub find_renames: Option<bool>,
+  /// Similarity (0-100) to consider a file renamed, defaults to 50.
+  pub rename_threshold: Option<u32>,
+  /// Stop at the first conflict instead of recording it in the index.
+  pub fail_on_conflict: Option<bool>,
+  pub file_favor: Option<FileFavor>,
+  /// Write conflict markers including the common ancestor.
+  pub diff3_style: Option<bool>,
+  pub ignore_whitespace: Option<bool>,
+  pub ignore_whitespace_change: Option<bool>,
+  pub ignore_whitespace_eol: Option<bool>,
+}
+
+impl From<&MergeOptions> for git2::MergeOptions {
+  fn from(value: &MergeOptions) -> Self {
+    let mut opts = git2::MergeOptions::new();
+    opts
+      .find_renames(value.find_renames.unwrap_or(true))
+      .fail_on_conflict(value.fail_on_conflict.unwrap_or(false))
+      .file_favor(value.file_favor.unwrap_or(FileFavor::Normal).into())
+      .diff3_style(value.diff3_style.unwrap_or(false))
+      .ignore_whitespace(value.ignore_whitespace.unwrap_or(false))
+      .ignore_whitespace_change(value.ignore_whitespace_change.unwrap_or(false))
+      .ignore_whitespace_eol(value.ignore_whitespace_eol.unwrap_or(false));
+
+    if let Some(rename_threshold) = value.rename_threshold {
+      opts.rename_threshold(rename_threshold);
+    }
+
+    opts
+  }
+}
+
+/// The user's `merge.ff` preference.
+#[napi]
+pub enum MergePreference {
+  None,
+  NoFastForward,
+  FastForwardOnly,
+}
+
+#[napi(object)]
+pub struct MergeAnalysis {
+  /// All the given commits are already reachable from HEAD.
+  pub up_to_date: bool,
+  /// HEAD can be fast-forwarded to the given commit.
+  pub fast_forward: bool,
+  /// The commits can be merged with a merge commit. Also set when a fast-forward is possible.
+  pub normal: bool,
+  /// HEAD points to a branch without commits yet.
+  pub unborn: bool,
+  pub preference: MergePreference,
+}
+
+impl From<(git2::MergeAnalysis, git2::MergePreference)> for MergeAnalysis {
+  fn from((analysis, preference): (git2::MergeAnalysis, git2::MergePreference)) -> Self {
+    Self {
+      up_to_date: analysis.is_up_to_date(),
+      fast_forward: analysis.is_fast_forward(),
+      normal: analysis.is_normal(),
+      unborn: analysis.is_unborn(),
+      preference: if preference.is_no_fast_forward() {
+        MergePreference::NoFastForward
+      } else if preference.is_fastforward_only() {
+        MergePreference::FastForwardOnly
+      } else {
+        MergePreference::None
+      },
+    }
+  }
+}
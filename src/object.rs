@@ -1,4 +1,5 @@
 use crate::commit::Commit;
+use crate::tag::Tag;
 use crate::tree::Tree;
 use napi::bindgen_prelude::*;
 use std::cmp::Ordering;
@@ -42,6 +43,27 @@ impl Oid {
   }
 }
 
+#[napi]
+pub enum ObjectType {
+  Any,
+  Commit,
+  Tree,
+  Blob,
+  Tag,
+}
+
+impl From<git2::ObjectType> for ObjectType {
+  fn from(value: git2::ObjectType) -> Self {
+    match value {
+      git2::ObjectType::Any => ObjectType::Any,
+      git2::ObjectType::Commit => ObjectType::Commit,
+      git2::ObjectType::Tree => ObjectType::Tree,
+      git2::ObjectType::Blob => ObjectType::Blob,
+      git2::ObjectType::Tag => ObjectType::Tag,
+    }
+  }
+}
+
 #[napi]
 pub struct Object {
   inner: git2::Object<'static>,
@@ -73,6 +95,16 @@ impl Object {
     self.inner.id().to_string()
   }
 
+  #[napi]
+  pub fn oid(&self) -> Oid {
+    Oid(self.inner.id())
+  }
+
+  #[napi]
+  pub fn kind(&self) -> Option<ObjectType> {
+    self.inner.kind().map(ObjectType::from)
+  }
+
   #[allow(clippy::inherent_to_string)]
   #[napi]
   pub fn as_tree(&self) -> Option<Tree> {
@@ -84,4 +116,9 @@ impl Object {
   pub fn as_commit(&self) -> Option<Commit> {
     self.inner.clone().into_commit().ok().map(Commit::from)
   }
+
+  #[napi]
+  pub fn as_tag(&self) -> Option<Tag> {
+    self.inner.clone().into_tag().ok().map(Tag::from)
+  }
 }
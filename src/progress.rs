@@ -0,0 +1,159 @@
+use crate::js_callback::JsCallback;
+use napi::threadsafe_function::ThreadSafeCallContext;
+use napi::{Env, JsFunction};
+use std::time::{Duration, Instant};
+
+/// Minimum delay between two progress notifications sent to JS.
+/// libgit2 reports progress for every single object, which would flood the event loop.
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct TransferProgress {
+  pub total_objects: u32,
+  pub indexed_objects: u32,
+  pub received_objects: u32,
+  pub local_objects: u32,
+  pub total_deltas: u32,
+  pub indexed_deltas: u32,
+  pub received_bytes: i64,
+}
+
+impl TransferProgress {
+  /// libgit2 also reports the initial, all-zero stats: those do not mean the transfer is complete.
+  pub(crate) fn is_done(&self) -> bool {
+    self.total_objects > 0
+      && self.received_objects == self.total_objects
+      && self.indexed_objects == self.total_objects
+      && self.indexed_deltas == self.total_deltas
+  }
+}
+
+impl From<git2::Progress<'_>> for TransferProgress {
+  fn from(value: git2::Progress<'_>) -> Self {
+    Self {
+      total_objects: value.total_objects() as u32,
+      indexed_objects: value.indexed_objects() as u32,
+      received_objects: value.received_objects() as u32,
+      local_objects: value.local_objects() as u32,
+      total_deltas: value.total_deltas() as u32,
+      indexed_deltas: value.indexed_deltas() as u32,
+      received_bytes: value.received_bytes() as i64,
+    }
+  }
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct PushTransferProgress {
+  pub current: u32,
+  pub total: u32,
+  pub bytes: i64,
+}
+
+impl PushTransferProgress {
+  pub(crate) fn is_done(&self) -> bool {
+    self.total > 0 && self.current == self.total
+  }
+}
+
+#[napi]
+pub enum PackBuilderStage {
+  AddingObjects,
+  Deltafication,
+}
+
+impl From<git2::PackBuilderStage> for PackBuilderStage {
+  fn from(value: git2::PackBuilderStage) -> Self {
+    match value {
+      git2::PackBuilderStage::AddingObjects => PackBuilderStage::AddingObjects,
+      git2::PackBuilderStage::Deltafication => PackBuilderStage::Deltafication,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct PackProgress {
+  pub stage: PackBuilderStage,
+  pub current: u32,
+  pub total: u32,
+}
+
+impl PackProgress {
+  pub(crate) fn is_done(&self) -> bool {
+    self.total > 0 && self.current == self.total
+  }
+}
+
+/// Forwards progress notifications to a JS callback, throttled to one every `PROGRESS_INTERVAL`.
+pub(crate) struct ProgressReporter<T: 'static> {
+  callback: JsCallback<T>,
+  last_call: Option<Instant>,
+}
+
+impl<T: 'static> ProgressReporter<T> {
+  pub(crate) fn new(callback: JsCallback<T>) -> Self {
+    Self {
+      callback,
+      last_call: None,
+    }
+  }
+
+  /// The final notification is waited for, so that it is delivered before the task resolves.
+  /// Returns false if the JS callback has thrown, to abort the operation.
+  pub(crate) fn report(&mut self, value: T, done: bool) -> bool {
+    if done {
+      self.callback.call(value, |_| Ok(())).is_ok()
+    } else if !matches!(self.last_call, Some(t) if t.elapsed() < PROGRESS_INTERVAL) {
+      self.last_call = Some(Instant::now());
+      self.callback.notify(value)
+    } else {
+      self.callback.error().is_none()
+    }
+  }
+}
+
+pub(crate) type TransferProgressCallback = JsCallback<TransferProgress>;
+pub(crate) type SidebandProgressCallback = JsCallback<String>;
+pub(crate) type PushTransferProgressCallback = JsCallback<PushTransferProgress>;
+pub(crate) type PackProgressCallback = JsCallback<PackProgress>;
+
+pub(crate) fn create_transfer_progress_callback(
+  env: &Env,
+  callback: JsFunction,
+) -> napi::Result<TransferProgressCallback> {
+  JsCallback::new(
+    env,
+    callback,
+    |ctx: ThreadSafeCallContext<TransferProgress>| Ok(vec![ctx.value]),
+  )
+}
+
+pub(crate) fn create_sideband_progress_callback(
+  env: &Env,
+  callback: JsFunction,
+) -> napi::Result<SidebandProgressCallback> {
+  JsCallback::new(env, callback, |ctx: ThreadSafeCallContext<String>| {
+    Ok(vec![ctx.value])
+  })
+}
+
+pub(crate) fn create_push_transfer_progress_callback(
+  env: &Env,
+  callback: JsFunction,
+) -> napi::Result<PushTransferProgressCallback> {
+  JsCallback::new(
+    env,
+    callback,
+    |ctx: ThreadSafeCallContext<PushTransferProgress>| Ok(vec![ctx.value]),
+  )
+}
+
+pub(crate) fn create_pack_progress_callback(
+  env: &Env,
+  callback: JsFunction,
+) -> napi::Result<PackProgressCallback> {
+  JsCallback::new(env, callback, |ctx: ThreadSafeCallContext<PackProgress>| {
+    Ok(vec![ctx.value])
+  })
+}
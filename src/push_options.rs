@@ -0,0 +1,45 @@
+use crate::abort::AbortFlag;
+use crate::credentials::create_credentials_callback;
+use crate::progress::{create_pack_progress_callback, create_push_transfer_progress_callback};
+use crate::remote::RemoteCallbacksOpts;
+use anyhow::Result;
+use napi::{Env, JsFunction, JsObject};
+
+/// Options for `Remote.push`.
+#[napi(object)]
+#[derive(Default)]
+pub struct PushOptions {
+  #[napi(ts_type = "(url: string, username?: string) => Credentials")]
+  pub credentials_callback: Option<JsFunction>,
+  #[napi(ts_type = "(progress: PushTransferProgress) => void")]
+  pub push_transfer_progress: Option<JsFunction>,
+  #[napi(ts_type = "(progress: PackProgress) => void")]
+  pub pack_progress: Option<JsFunction>,
+  #[napi(ts_type = "AbortSignal")]
+  pub signal: Option<JsObject>,
+}
+
+impl PushOptions {
+  pub fn into_callbacks(self, env: &Env) -> Result<RemoteCallbacksOpts> {
+    Ok(RemoteCallbacksOpts {
+      credentials: self
+        .credentials_callback
+        .map(|cred_cb| create_credentials_callback(env, cred_cb))
+        .transpose()?,
+      push_transfer_progress: self
+        .push_transfer_progress
+        .map(|f| create_push_transfer_progress_callback(env, f))
+        .transpose()?,
+      pack_progress: self
+        .pack_progress
+        .map(|f| create_pack_progress_callback(env, f))
+        .transpose()?,
+      abort: self
+        .signal
+        .map(|s| AbortFlag::from_signal(env, s))
+        .transpose()?
+        .unwrap_or_default(),
+      ..Default::default()
+    })
+  }
+}
@@ -0,0 +1,287 @@
+use crate::annotated_commit::AnnotatedCommit;
+use crate::checkout::CheckoutOptions;
+use crate::commit::Signature;
+use crate::index::Index;
+use crate::merge::MergeOptions;
+use crate::object::Oid;
+use crate::repository::Repository;
+use napi::bindgen_prelude::*;
+use napi::tokio::sync::Mutex;
+use napi::JsObject;
+
+#[napi]
+pub enum RebaseOperationType {
+  Pick,
+  Reword,
+  Edit,
+  Squash,
+  Fixup,
+  Exec,
+}
+
+impl From<git2::RebaseOperationType> for RebaseOperationType {
+  fn from(value: git2::RebaseOperationType) -> Self {
+    match value {
+      git2::RebaseOperationType::Pick => RebaseOperationType::Pick,
+      git2::RebaseOperationType::Reword => RebaseOperationType::Reword,
+      git2::RebaseOperationType::Edit => RebaseOperationType::Edit,
+      git2::RebaseOperationType::Squash => RebaseOperationType::Squash,
+      git2::RebaseOperationType::Fixup => RebaseOperationType::Fixup,
+      git2::RebaseOperationType::Exec => RebaseOperationType::Exec,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct RebaseOperation {
+  /// The position of the operation in the rebase.
+  pub index: u32,
+  pub kind: RebaseOperationType,
+  /// The commit being applied.
+  pub id: Oid,
+  pub exec: Option<String>,
+}
+
+impl RebaseOperation {
+  pub(crate) fn new(index: usize, operation: &git2::RebaseOperation<'_>) -> Self {
+    let kind = operation
+      .kind()
+      .map(RebaseOperationType::from)
+      .unwrap_or(RebaseOperationType::Pick);
+
+    Self {
+      index: index as u32,
+      kind,
+      id: Oid(operation.id()),
+      // git2 panics when reading the command of non-exec operations.
+      exec: match kind {
+        RebaseOperationType::Exec => operation.exec().map(ToString::to_string),
+        _ => None,
+      },
+    }
+  }
+}
+
+/// An operation of an in-memory rebase which does not apply cleanly.
+#[napi(object, object_from_js = false)]
+pub struct RebaseConflict {
+  pub operation: RebaseOperation,
+  pub paths: Vec<String>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct InMemoryRebaseResult {
+  /// The commits created for the operations applied so far.
+  pub commits: Vec<Oid>,
+  /// The new tip of the rebased branch, set only if all the operations applied cleanly.
+  pub head: Option<Oid>,
+  /// The name of the reference moved to the new tip. This is null if the rebase stopped on
+  /// a conflict, or if the rebased branch was not given by a reference (e.g. an annotated
+  /// commit looked up by id, or a detached HEAD): in that case no reference is updated and
+  /// the caller is responsible for pointing one at `head`.
+  pub updated_ref: Option<String>,
+  /// The first operation which could not be applied, stopping the rebase.
+  pub conflict: Option<RebaseConflict>,
+}
+
+/// Options for `Repository.rebase` and `Repository.openRebase`.
+#[napi(object, object_to_js = false)]
+#[derive(Default)]
+pub struct RebaseOptions {
+  /// The branch to rebase. Defaults to HEAD.
+  pub branch: Option<Reference<AnnotatedCommit>>,
+  /// The commit the branch has been forked from. Defaults to rebasing all the reachable commits.
+  pub upstream: Option<Reference<AnnotatedCommit>>,
+  /// The commit to rebase onto. Defaults to upstream.
+  pub onto: Option<Reference<AnnotatedCommit>>,
+  /// Do not write rebase information for other git tools to inspect.
+  pub quiet: Option<bool>,
+  /// Rebase without touching the index and the working directory.
+  /// Results and conflicts are only available through `Rebase.index`.
+  pub inmemory: Option<bool>,
+  pub merge_options: Option<MergeOptions>,
+  pub checkout_options: Option<CheckoutOptions>,
+}
+
+impl RebaseOptions {
+  pub(crate) fn to_git_rebase_opts(&self) -> git2::RebaseOptions<'static> {
+    let mut opts = git2::RebaseOptions::new();
+    opts
+      .quiet(self.quiet.unwrap_or(false))
+      .inmemory(self.inmemory.unwrap_or(false));
+
+    if let Some(merge_options) = &self.merge_options {
+      opts.merge_options(merge_options.into());
+    }
+
+    if let Some(checkout_options) = &self.checkout_options {
+      opts.checkout_options(checkout_options.to_builder());
+    }
+
+    opts
+  }
+}
+
+/// A rebase in progress. Operations are applied one at a time with `next`,
+/// then committed with `commit` once any conflict in the index has been resolved.
+#[napi]
+pub struct Rebase {
+  inner: Mutex<git2::Rebase<'static>>,
+  repository: Reference<Repository>,
+  inmemory: bool,
+}
+
+unsafe impl Send for Rebase {}
+unsafe impl Sync for Rebase {}
+
+impl Rebase {
+  pub(crate) fn new(
+    rebase: git2::Rebase<'_>,
+    repository: Reference<Repository>,
+    inmemory: bool,
+  ) -> Self {
+    Self {
+      inner: Mutex::new(unsafe { std::mem::transmute(rebase) }),
+      repository,
+      inmemory,
+    }
+  }
+}
+
+#[napi]
+impl Rebase {
+  #[napi]
+  pub async fn operations(&self) -> Vec<RebaseOperation> {
+    let mut inner = self.inner.lock().await;
+    (0..inner.len())
+      .filter_map(|idx| inner.nth(idx).map(|op| RebaseOperation::new(idx, &op)))
+      .collect()
+  }
+
+  /// The operation being applied, or null if `next` has not been called yet.
+  #[napi]
+  pub async fn current_operation(&self) -> Option<RebaseOperation> {
+    let mut inner = self.inner.lock().await;
+    let idx = inner.operation_current()?;
+    inner.nth(idx).map(|op| RebaseOperation::new(idx, &op))
+  }
+
+  /// Applies the next operation, leaving any conflict in the index.
+  /// Resolves to null when all the operations have been applied.
+  #[napi]
+  pub async fn next(&self) -> Result<Option<RebaseOperation>> {
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    match inner.next() {
+      Some(Ok(_)) => {}
+      Some(Err(e)) => return Err(anyhow::Error::from(e).into()),
+      None => return Ok(None),
+    };
+
+    Ok(
+      inner
+        .operation_current()
+        .and_then(|idx| inner.nth(idx).map(|op| RebaseOperation::new(idx, &op))),
+    )
+  }
+
+  /// Commits the current operation. Author and message default to the ones of the original commit.
+  /// Resolves to null if the changes have already been applied upstream.
+  #[napi(ts_return_type = "Promise<Oid | null>")]
+  pub fn commit(
+    &self,
+    committer: ClassInstance<Signature>,
+    author: Option<ClassInstance<Signature>>,
+    message: Option<String>,
+    this: Reference<Rebase>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let committer = committer.as_ref().clone();
+    let author = author.map(|a| a.as_ref().clone());
+
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_commit(committer, author, message).await {
+        Ok(oid) => deferred.resolve(move |_| Ok(oid)),
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
+  }
+
+  /// Aborts the rebase, resetting the repository and the working directory to their original state.
+  #[napi]
+  pub async fn abort(&self) -> Result<()> {
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    inner.abort().map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Finishes a rebase once all the operations have been applied and committed.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn finish(
+    &self,
+    signature: Option<ClassInstance<Signature>>,
+    this: Reference<Rebase>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let signature = signature.map(|s| s.as_ref().clone());
+
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_finish(signature).await {
+        Ok(()) => deferred.resolve(|_| Ok(())),
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
+  }
+
+  /// The index holding the result of the current operation, including its conflicts.
+  #[napi]
+  pub async fn index(&self) -> Result<Index> {
+    let repository = self.repository.repository.lock().await;
+    let index = if self.inmemory {
+      let mut inner = self.inner.lock().await;
+      inner.inmemory_index()
+    } else {
+      repository.index()
+    };
+
+    Ok(Index::from(index.map_err(anyhow::Error::from)?))
+  }
+}
+
+impl Rebase {
+  async fn internal_commit(
+    &self,
+    committer: Signature,
+    author: Option<Signature>,
+    message: Option<String>,
+  ) -> anyhow::Result<Option<Oid>> {
+    let committer = git2::Signature::try_from(committer)?;
+    let author = author.map(git2::Signature::try_from).transpose()?;
+
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    match inner.commit(author.as_ref(), &committer, message.as_deref()) {
+      Ok(oid) => Ok(Some(Oid(oid))),
+      Err(e) if e.code() == git2::ErrorCode::Applied => Ok(None),
+      Err(e) => Err(e.into()),
+    }
+  }
+
+  async fn internal_finish(&self, signature: Option<Signature>) -> anyhow::Result<()> {
+    let signature = signature.map(git2::Signature::try_from).transpose()?;
+
+    let _repository = self.repository.repository.lock().await;
+    let mut inner = self.inner.lock().await;
+    inner.finish(signature.as_ref())?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,425 @@
+use crate::sequencer;
+use anyhow::{anyhow, bail};
+
+#[napi]
+pub enum RebasePlanAction {
+  Pick,
+  /// Pick the commit, replacing its message with the one of the step.
+  Reword,
+  /// Pick the commit, then pause to allow amending it.
+  Edit,
+  /// Meld the commit into the previous one, concatenating the messages.
+  Squash,
+  /// Meld the commit into the previous one, discarding its message.
+  Fixup,
+  Drop,
+}
+
+#[napi]
+pub enum RebasePlanStatus {
+  /// The plan has not been started yet.
+  Pending,
+  /// Paused after committing an edit step.
+  Edit,
+  /// Paused on a step which does not apply cleanly: conflicts have to be resolved in the index.
+  Conflict,
+  Done,
+}
+
+#[napi(object)]
+pub struct RebasePlanStep {
+  pub action: RebasePlanAction,
+  /// The id of the commit, as an hex string.
+  pub id: String,
+  pub summary: String,
+  /// The message to use for reword and squash steps. Defaults to the original one.
+  pub message: Option<String>,
+}
+
+/// An interactive rebase todo list, executed with `Repository.runRebasePlan`.
+/// Commit ids are hex strings, so that the plan can be serialized and resumed later.
+#[napi(object)]
+pub struct RebasePlan {
+  /// The branch updated once all the steps have been executed. Null for a detached HEAD.
+  pub branch: Option<String>,
+  pub orig_head: String,
+  pub onto: String,
+  pub todo: Vec<RebasePlanStep>,
+  /// The step the plan is paused on, already removed from the todo list.
+  pub current: Option<RebasePlanStep>,
+  pub status: RebasePlanStatus,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct RebasePlanOptions {
+  /// The commit to rebase onto. Defaults to upstream.
+  pub onto: Option<String>,
+  /// The branch to rebase. Defaults to the current one.
+  pub branch: Option<String>,
+  /// Move `fixup!` and `squash!` commits right after the commit they refer to.
+  pub autosquash: Option<bool>,
+}
+
+fn parse_oid(id: &str) -> anyhow::Result<git2::Oid> {
+  Ok(git2::Oid::from_str(id)?)
+}
+
+fn head_commit(repository: &git2::Repository) -> anyhow::Result<git2::Commit<'_>> {
+  Ok(repository.head()?.peel_to_commit()?)
+}
+
+/// Returns the action and the target subject of `fixup!` and `squash!` commits.
+fn autosquash_target(summary: &str) -> Option<(RebasePlanAction, &str)> {
+  let (action, mut subject) = if let Some(subject) = summary.strip_prefix("fixup! ") {
+    (RebasePlanAction::Fixup, subject)
+  } else if let Some(subject) = summary.strip_prefix("squash! ") {
+    (RebasePlanAction::Squash, subject)
+  } else {
+    return None;
+  };
+
+  while let Some(rest) = subject
+    .strip_prefix("fixup! ")
+    .or_else(|| subject.strip_prefix("squash! "))
+  {
+    subject = rest;
+  }
+
+  Some((action, subject))
+}
+
+/// Returns the index of the group targeted by a `fixup!` or `squash!` subject.
+/// The subject is matched against the commit summaries first, then used as a commit id
+/// only if it is an (at least 4 characters long) hex prefix identifying a single commit of the plan.
+fn autosquash_group(
+  groups: &[(RebasePlanStep, Vec<RebasePlanStep>)],
+  ids: &[String],
+  subject: &str,
+) -> Option<usize> {
+  if let Some(position) = groups
+    .iter()
+    .position(|(target, _)| target.summary == subject)
+  {
+    return Some(position);
+  }
+
+  if subject.len() < 4 || !subject.chars().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+
+  let prefix = subject.to_ascii_lowercase();
+  if ids.iter().filter(|id| id.starts_with(&prefix)).count() != 1 {
+    return None;
+  }
+
+  groups
+    .iter()
+    .position(|(target, _)| target.id.starts_with(&prefix))
+}
+
+fn autosquash(steps: Vec<RebasePlanStep>) -> Vec<RebasePlanStep> {
+  let ids = steps.iter().map(|step| step.id.clone()).collect::<Vec<_>>();
+  let mut groups: Vec<(RebasePlanStep, Vec<RebasePlanStep>)> = vec![];
+  for mut step in steps {
+    if let Some((action, subject)) = autosquash_target(&step.summary) {
+      if let Some(position) = autosquash_group(&groups, &ids, subject) {
+        step.action = action;
+        groups[position].1.push(step);
+        continue;
+      }
+    }
+
+    groups.push((step, vec![]));
+  }
+
+  groups
+    .into_iter()
+    .flat_map(|(step, fixups)| std::iter::once(step).chain(fixups))
+    .collect()
+}
+
+/// Checks that every squash and fixup step follows a step creating a commit.
+fn validate(todo: &[RebasePlanStep], mut has_commit: bool) -> anyhow::Result<()> {
+  for step in todo {
+    match step.action {
+      RebasePlanAction::Pick | RebasePlanAction::Reword | RebasePlanAction::Edit => {
+        has_commit = true
+      }
+      RebasePlanAction::Squash if !has_commit => {
+        bail!("cannot 'squash' without a previous commit")
+      }
+      RebasePlanAction::Fixup if !has_commit => bail!("cannot 'fixup' without a previous commit"),
+      _ => {}
+    }
+  }
+
+  Ok(())
+}
+
+/// Builds a plan picking all the commits reachable from the branch and not from upstream.
+/// Merge commits are left out, as git does.
+pub(crate) fn create(
+  repository: &git2::Repository,
+  upstream: &str,
+  options: &RebasePlanOptions,
+) -> anyhow::Result<RebasePlan> {
+  let upstream = repository.revparse_single(upstream)?.peel_to_commit()?;
+  let onto = match &options.onto {
+    Some(onto) => repository.revparse_single(onto)?.peel_to_commit()?,
+    None => upstream.clone(),
+  };
+
+  let (branch, head) = match &options.branch {
+    Some(name) => {
+      let reference = repository.resolve_reference_from_short_name(name)?;
+      (
+        reference.name().map(ToString::to_string),
+        reference.peel_to_commit()?,
+      )
+    }
+    None => {
+      let head = repository.head()?;
+      let name = head
+        .is_branch()
+        .then(|| head.name().map(ToString::to_string))
+        .flatten();
+
+      (name, head.peel_to_commit()?)
+    }
+  };
+
+  let mut revwalk = repository.revwalk()?;
+  revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+  revwalk.push(head.id())?;
+  revwalk.hide(upstream.id())?;
+
+  let mut todo = vec![];
+  for oid in revwalk {
+    let commit = repository.find_commit(oid?)?;
+    if commit.parent_count() > 1 {
+      continue;
+    }
+
+    todo.push(RebasePlanStep {
+      action: RebasePlanAction::Pick,
+      id: commit.id().to_string(),
+      summary: commit.summary().unwrap_or_default().to_string(),
+      message: None,
+    });
+  }
+
+  if options.autosquash.unwrap_or(false) {
+    todo = autosquash(todo);
+  }
+
+  validate(&todo, false)?;
+
+  Ok(RebasePlan {
+    branch,
+    orig_head: head.id().to_string(),
+    onto: onto.id().to_string(),
+    todo,
+    current: None,
+    status: RebasePlanStatus::Pending,
+  })
+}
+
+/// Starts the plan, checking out onto with a detached HEAD.
+/// Like git, refuses to start if the index or the working directory have uncommitted changes.
+fn start(repository: &git2::Repository, plan: &RebasePlan) -> anyhow::Result<()> {
+  if repository.state() != git2::RepositoryState::Clean || sequencer::is_in_progress(repository) {
+    bail!("Another operation is in progress");
+  }
+
+  if let Some(branch) = &plan.branch {
+    let reference = repository.find_reference(branch)?;
+    if reference.peel_to_commit()?.id() != parse_oid(&plan.orig_head)? {
+      bail!(
+        "The branch {} has been updated since the plan was created",
+        branch
+      );
+    }
+  }
+
+  let mut opts = git2::StatusOptions::new();
+  opts.include_untracked(false).include_ignored(false);
+  if !repository.statuses(Some(&mut opts))?.is_empty() {
+    bail!("The index or the working directory have uncommitted changes");
+  }
+
+  let onto = repository.find_commit(parse_oid(&plan.onto)?)?;
+  repository.checkout_tree(
+    onto.as_object(),
+    Some(git2::build::CheckoutBuilder::new().safe()),
+  )?;
+  repository.set_head_detached(onto.id())?;
+
+  Ok(())
+}
+
+/// Commits the changes applied to the index for the given step.
+/// Squash and fixup steps amend HEAD, while steps whose changes are already
+/// present in HEAD do not produce a commit.
+fn commit_step(
+  repository: &git2::Repository,
+  onto: &str,
+  step: &RebasePlanStep,
+) -> anyhow::Result<()> {
+  let mut index = repository.index()?;
+  if index.has_conflicts() {
+    bail!("Conflicts must be resolved before continuing");
+  }
+
+  let head = head_commit(repository)?;
+  let tree = repository.find_tree(index.write_tree()?)?;
+  let original = repository.find_commit(parse_oid(&step.id)?)?;
+  let committer = repository.signature()?;
+
+  match step.action {
+    RebasePlanAction::Squash | RebasePlanAction::Fixup => {
+      if head.id() == parse_oid(onto)? {
+        let action = match step.action {
+          RebasePlanAction::Squash => "squash",
+          _ => "fixup",
+        };
+
+        bail!("cannot '{}' without a previous commit", action);
+      }
+
+      let message = match (step.action, &step.message) {
+        (RebasePlanAction::Fixup, _) => head.message().unwrap_or_default().to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => format!(
+          "{}\n\n{}",
+          head.message().unwrap_or_default().trim_end(),
+          original.message().unwrap_or_default()
+        ),
+      };
+
+      head.amend(
+        Some("HEAD"),
+        None,
+        Some(&committer),
+        None,
+        Some(&message),
+        Some(&tree),
+      )?;
+    }
+    _ if tree.id() == head.tree_id() => {}
+    _ => {
+      let message = match (step.action, &step.message) {
+        (RebasePlanAction::Reword, Some(message)) => message.clone(),
+        _ => original.message().unwrap_or_default().to_string(),
+      };
+
+      repository.commit(
+        Some("HEAD"),
+        &original.author(),
+        &committer,
+        &message,
+        &tree,
+        &[&head],
+      )?;
+    }
+  }
+
+  sequencer::cleanup_step(repository)
+}
+
+/// Points the branch to the rebased commits and checks it out again.
+fn finish(repository: &git2::Repository, plan: &RebasePlan) -> anyhow::Result<()> {
+  let Some(branch) = &plan.branch else {
+    return Ok(());
+  };
+
+  let head = head_commit(repository)?;
+  repository.reference_matching(
+    branch,
+    head.id(),
+    true,
+    parse_oid(&plan.orig_head)?,
+    "rebase (plan): finished",
+  )?;
+  repository.set_head(branch)?;
+
+  Ok(())
+}
+
+/// Executes the plan until it completes or pauses on an edit or conflict step.
+/// The commits are cherry-picked one at a time onto a detached HEAD, the plan itself
+/// holding the state needed to resume or abort it.
+pub(crate) fn run(repository: &git2::Repository, plan: &mut RebasePlan) -> anyhow::Result<()> {
+  validate(
+    &plan.todo,
+    !matches!(plan.status, RebasePlanStatus::Pending),
+  )?;
+
+  match plan.status {
+    RebasePlanStatus::Pending => start(repository, plan)?,
+    RebasePlanStatus::Conflict => {
+      let step = plan
+        .current
+        .take()
+        .ok_or_else(|| anyhow!("No current rebase step"))?;
+
+      commit_step(repository, &plan.onto, &step)?;
+      if matches!(step.action, RebasePlanAction::Edit) {
+        plan.current = Some(step);
+        plan.status = RebasePlanStatus::Edit;
+        return Ok(());
+      }
+    }
+    RebasePlanStatus::Edit => plan.current = None,
+    RebasePlanStatus::Done => bail!("The rebase plan has already been completed"),
+  };
+
+  while !plan.todo.is_empty() {
+    let step = plan.todo.remove(0);
+    if matches!(step.action, RebasePlanAction::Drop) {
+      continue;
+    }
+
+    let commit = repository.find_commit(parse_oid(&step.id)?)?;
+    repository.cherrypick(&commit, None)?;
+
+    if repository.index()?.has_conflicts() {
+      plan.current = Some(step);
+      plan.status = RebasePlanStatus::Conflict;
+      return Ok(());
+    }
+
+    commit_step(repository, &plan.onto, &step)?;
+    if matches!(step.action, RebasePlanAction::Edit) {
+      plan.current = Some(step);
+      plan.status = RebasePlanStatus::Edit;
+      return Ok(());
+    }
+  }
+
+  finish(repository, plan)?;
+
+  plan.current = None;
+  plan.status = RebasePlanStatus::Done;
+
+  Ok(())
+}
+
+/// Stops a started plan, resetting the branch, the index and the working directory to their original state.
+pub(crate) fn abort(repository: &git2::Repository, plan: &RebasePlan) -> anyhow::Result<()> {
+  match plan.status {
+    RebasePlanStatus::Pending => bail!("The rebase plan has not been started"),
+    RebasePlanStatus::Done => bail!("The rebase plan has already been completed"),
+    _ => {}
+  }
+
+  let orig_head = repository.find_commit(parse_oid(&plan.orig_head)?)?;
+  match &plan.branch {
+    Some(branch) => repository.set_head(branch)?,
+    None => repository.set_head_detached(orig_head.id())?,
+  }
+
+  // A hard reset also removes the state files of the step the plan is paused on.
+  repository.reset(orig_head.as_object(), git2::ResetType::Hard, None)?;
+  Ok(())
+}
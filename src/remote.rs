@@ -1,13 +1,23 @@
-use crate::credentials::Credentials;
+use crate::abort::{aborted_error, AbortFlag};
+use crate::credentials::{create_credentials_callback, request_credentials, CredentialsCallback};
+use crate::fetch_options::DownloadOptions;
 use crate::object::Oid;
+use crate::progress::{
+  PackProgress, PackProgressCallback, ProgressReporter, PushTransferProgress,
+  PushTransferProgressCallback, SidebandProgressCallback, TransferProgress,
+  TransferProgressCallback,
+};
+use crate::push_options::PushOptions;
 use crate::task::{ConnectRemote, PullRemote, PushRemote};
 use crate::Direction;
 use anyhow::Result;
-use git2::{AutotagOption, FetchOptions, PushOptions, RemoteCallbacks, RemoteConnection};
+use git2::{AutotagOption, FetchOptions, RemoteCallbacks, RemoteConnection};
 use napi::bindgen_prelude::*;
 use napi::tokio::sync::Mutex;
-use napi::JsUnknown;
+use napi::JsObject;
+use std::cell::RefCell;
 use std::pin::Pin;
+use std::rc::Rc;
 
 pub struct RemoteConn(RemoteConnection<'static, 'static, 'static>);
 unsafe impl Send for RemoteConn {}
@@ -64,18 +74,42 @@ impl Remote {
   pub(crate) fn internal_push(
     &self,
     ref_specs: &[String],
-    remote_callbacks: RemoteCallbacks,
-  ) -> Result<()> {
-    let mut po = PushOptions::default();
+    mut remote_callbacks: RemoteCallbacks,
+  ) -> Result<Vec<PushResult>> {
+    let results = Rc::new(RefCell::new(vec![]));
+    {
+      let results = results.clone();
+      remote_callbacks.push_update_reference(move |ref_name, status| {
+        results.borrow_mut().push(PushResult {
+          ref_name: ref_name.to_string(),
+          accepted: status.is_none(),
+          message: status.map(ToString::to_string),
+        });
+
+        Ok(())
+      });
+    }
+
+    let mut po = git2::PushOptions::default();
     po.remote_callbacks(remote_callbacks);
 
     let mut remote = futures::executor::block_on(self.inner.lock());
     remote.push(ref_specs, Some(&mut po))?;
+    drop(po);
 
-    Ok(())
+    Ok(results.take())
   }
 }
 
+/// The outcome of the update of a single remote reference.
+#[napi(object)]
+pub struct PushResult {
+  pub ref_name: String,
+  pub accepted: bool,
+  /// The rejection message sent by the server, if any.
+  pub message: Option<String>,
+}
+
 #[napi]
 pub struct RemoteHead {
   name: String,
@@ -83,47 +117,129 @@ pub struct RemoteHead {
   loid: Option<Oid>,
 }
 
+/// JS callbacks to be wired into the git2 remote callbacks.
+#[derive(Clone, Default)]
+pub struct RemoteCallbacksOpts {
+  pub credentials: Option<CredentialsCallback>,
+  pub transfer_progress: Option<TransferProgressCallback>,
+  pub sideband_progress: Option<SidebandProgressCallback>,
+  pub push_transfer_progress: Option<PushTransferProgressCallback>,
+  pub pack_progress: Option<PackProgressCallback>,
+  pub abort: AbortFlag,
+}
+
+impl RemoteCallbacksOpts {
+  /// Converts the error of a failed operation, reporting the abort or
+  /// the error thrown by a JS callback instead of the generic libgit2 error.
+  pub(crate) fn map_error(&self, error: anyhow::Error) -> napi::Error {
+    if self.abort.is_aborted() {
+      return aborted_error();
+    }
+
+    let thrown = self
+      .transfer_progress
+      .as_ref()
+      .and_then(|cb| cb.error())
+      .or_else(|| self.sideband_progress.as_ref().and_then(|cb| cb.error()));
+
+    match thrown {
+      Some(message) => napi::Error::from_reason(message),
+      None => error.into(),
+    }
+  }
+}
+
 impl Remote {
   pub(crate) fn prepare_remote_callbacks(
-    credentials_callback: Option<JsFunction>,
-    env: &Env,
-  ) -> Result<git2::RemoteCallbacks> {
+    opts: RemoteCallbacksOpts,
+  ) -> git2::RemoteCallbacks<'static> {
     let mut cb = git2::RemoteCallbacks::default();
-    if let Some(cred_cb) = credentials_callback {
+    let abort = opts.abort;
+
+    if let Some(cred_cb) = opts.credentials {
+      let abort = abort.clone();
       cb.credentials(move |url, username, _| {
-        cred_cb
-          .call::<JsUnknown>(
-            None,
-            &[
-              env
-                .create_string(url)
-                .map_err(|e| git2::Error::from_str(&e.reason))?
-                .into_unknown(),
-              if let Some(username) = username {
-                env
-                  .create_string(username)
-                  .map_err(|e| git2::Error::from_str(&e.reason))?
-                  .into_unknown()
-              } else {
-                env
-                  .get_undefined()
-                  .map_err(|e| git2::Error::from_str(&e.reason))?
-                  .into_unknown()
-              },
-            ],
-          )
-          .map_err(|e| git2::Error::from_str(&e.reason))
-          .and_then(|c| {
-            ClassInstance::from_unknown(c).map_err(|e| git2::Error::from_str(&e.reason))
-          })
-          .and_then(|c: ClassInstance<Credentials>| {
-            c.to_cred()
-              .map_err(|e| git2::Error::from_str(&e.to_string()))
-          })
+        if abort.is_aborted() {
+          return Err(git2::Error::from_str("aborted"));
+        }
+
+        request_credentials(&cred_cb, url, username)
       });
     }
 
-    Ok(cb)
+    {
+      let abort = abort.clone();
+      let mut reporter = opts.transfer_progress.map(ProgressReporter::new);
+      cb.transfer_progress(move |stats| {
+        if abort.is_aborted() {
+          return false;
+        }
+
+        let Some(reporter) = reporter.as_mut() else {
+          return true;
+        };
+
+        let progress = TransferProgress::from(stats);
+        let done = progress.is_done();
+
+        reporter.report(progress, done)
+      });
+    }
+
+    {
+      let abort = abort.clone();
+      let sideband_cb = opts.sideband_progress;
+      cb.sideband_progress(move |data| {
+        if abort.is_aborted() {
+          return false;
+        }
+
+        match &sideband_cb {
+          Some(cb) => cb.notify(String::from_utf8_lossy(data).to_string()),
+          None => true,
+        }
+      });
+    }
+
+    if let Some(progress_cb) = opts.push_transfer_progress {
+      let mut reporter = ProgressReporter::new(progress_cb);
+      cb.push_transfer_progress(move |current, total, bytes| {
+        let progress = PushTransferProgress {
+          current: current as u32,
+          total: total as u32,
+          bytes: bytes as i64,
+        };
+
+        // The callback cannot abort the push: errors thrown by JS are ignored.
+        let done = progress.is_done();
+        reporter.report(progress, done);
+      });
+    }
+
+    if let Some(progress_cb) = opts.pack_progress {
+      let mut reporter = ProgressReporter::new(progress_cb);
+      cb.pack_progress(move |stage, current, total| {
+        let progress = PackProgress {
+          stage: stage.into(),
+          current: current as u32,
+          total: total as u32,
+        };
+
+        let done = progress.is_done();
+        reporter.report(progress, done);
+      });
+    }
+
+    // Last chance to abort a push before the pack is sent.
+    cb.push_negotiation(move |_| {
+      if abort.is_aborted() {
+        Err(git2::Error::from_str("aborted"))
+      } else {
+        Ok(())
+      }
+    });
+
+    cb
   }
 }
 
@@ -135,16 +251,24 @@ impl Remote {
     direction: Direction,
     #[napi(ts_arg_type = "(url: string, username?: string) => Credentials")]
     credentials_callback: Option<JsFunction>,
+    #[napi(ts_arg_type = "AbortSignal")] signal: Option<JsObject>,
     env: Env,
     this: Reference<Remote>,
   ) -> napi::Result<AsyncTask<ConnectRemote>> {
-    let cb_ref = if let Some(f) = credentials_callback {
-      Some(env.create_reference(f)?)
-    } else {
-      None
+    let callbacks = RemoteCallbacksOpts {
+      credentials: credentials_callback
+        .map(|f| create_credentials_callback(&env, f))
+        .transpose()?,
+      abort: signal
+        .map(|s| AbortFlag::from_signal(&env, s))
+        .transpose()?
+        .unwrap_or_default(),
+      ..Default::default()
     };
 
-    Ok(AsyncTask::new(ConnectRemote::new(this, direction, cb_ref)))
+    Ok(AsyncTask::new(ConnectRemote::new(
+      this, direction, callbacks,
+    )))
   }
 
   #[napi]
@@ -175,40 +299,30 @@ impl Remote {
     )
   }
 
-  #[napi(ts_return_type = "Promise<void>")]
+  #[napi(ts_return_type = "Promise<Array<PushResult>>")]
   pub fn push(
     &self,
     ref_specs: Vec<String>,
-    #[napi(ts_arg_type = "(url: string, username?: string) => Credentials")]
-    credentials_callback: Option<JsFunction>,
+    options: Option<PushOptions>,
     env: Env,
     this: Reference<Remote>,
   ) -> napi::Result<AsyncTask<PushRemote>> {
-    let cb_ref = if let Some(f) = credentials_callback {
-      Some(env.create_reference(f)?)
-    } else {
-      None
-    };
+    let callbacks = options.unwrap_or_default().into_callbacks(&env)?;
 
-    Ok(AsyncTask::new(PushRemote::new(this, ref_specs, cb_ref)))
+    Ok(AsyncTask::new(PushRemote::new(this, ref_specs, callbacks)))
   }
 
   #[napi(ts_return_type = "Promise<void>")]
   pub fn download(
     &self,
     ref_specs: Vec<String>,
-    #[napi(ts_arg_type = "(url: string, username?: string) => Credentials")]
-    credentials_callback: Option<JsFunction>,
+    options: Option<DownloadOptions>,
     env: Env,
     this: Reference<Remote>,
   ) -> napi::Result<AsyncTask<PullRemote>> {
-    let cb_ref = if let Some(f) = credentials_callback {
-      Some(env.create_reference(f)?)
-    } else {
-      None
-    };
+    let callbacks = options.unwrap_or_default().into_callbacks(&env)?;
 
-    Ok(AsyncTask::new(PullRemote::new(this, ref_specs, cb_ref)))
+    Ok(AsyncTask::new(PullRemote::new(this, ref_specs, callbacks)))
   }
 }
 
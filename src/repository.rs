@@ -1,18 +1,38 @@
+use crate::annotated_commit::AnnotatedCommit;
+use crate::blame::BlameOptions;
+use crate::branch::{AheadBehind, BranchType};
+use crate::checkout::CheckoutOptions;
+use crate::cherrypick::CherrypickOptions;
 use crate::commit::{Commit, Signature};
 use crate::config::Config;
+use crate::describe::DescribeOptions;
+use crate::diff::{ApplyLocation, ApplyOptions, Diff, DiffOptions};
 use crate::fetch_options::FetchOptions;
 use crate::index::Index;
-use crate::object::Oid;
+use crate::log::LogOptions;
+use crate::merge::MergeOptions;
+use crate::object::{Object, Oid};
+use crate::rebase::RebaseOptions;
+use crate::rebase_plan::{RebasePlan, RebasePlanOptions};
 use crate::reference::ReferenceType;
 use crate::remote::Remote;
-use crate::revwalk::Revwalk;
+use crate::revparse::{RevparseExt, Revspec};
+use crate::revwalk::{Revwalk, RevwalkHandle};
+use crate::sequencer::SequencerAction;
+use crate::stash::{StashApplyOptions, StashEntry, StashFlags};
+use crate::status::{StatusEntry, StatusOptions};
+use crate::tag::Tag;
 use crate::task::{
-  BranchNameRef, CloneRepository, CreateCommit, FetchRepository, GetBranchCommit, InitRepository,
-  OpenRepository,
+  AnalyzeMerge, ApplyDiff, ApplyDiffToTree, ApplyStash, BlameFile, BranchNameRef,
+  BranchTrackingSummary, CloneRepository, CreateCommit, CreateDiff, CreateRebasePlan, CreateTag,
+  DiffTarget, FetchRepository, GetBranchCommit, InitRepository, ListBranches,
+  MergeAnnotatedCommits, MergeCommits, MergeTrees, OpenRepository, PickAction, PickCommit,
+  PickCommitInMemory, QueryLog, RebaseInMemory, RunRebasePlan, SaveStash, StartRebase,
+  StartSequence,
 };
 use crate::tree::Tree;
 use crate::{RepositoryState, ResetType};
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use git2::build::CheckoutBuilder;
 use napi::bindgen_prelude::*;
 use napi::tokio::sync::Mutex;
@@ -50,6 +70,88 @@ impl Repository {
     Ok(Commit::from(commit))
   }
 
+  async fn internal_find_tag(&self, target: Oid) -> anyhow::Result<Tag> {
+    let repository = self.repository.lock().await;
+    let tag = repository.find_tag(target.0)?;
+
+    Ok(Tag::from(tag))
+  }
+
+  async fn internal_graph_ahead_behind(
+    &self,
+    local: Oid,
+    upstream: Oid,
+  ) -> anyhow::Result<AheadBehind> {
+    let repository = self.repository.lock().await;
+    Ok(repository.graph_ahead_behind(local.0, upstream.0)?.into())
+  }
+
+  async fn internal_merge_base(&self, one: Oid, two: Oid) -> anyhow::Result<Oid> {
+    let repository = self.repository.lock().await;
+    Ok(Oid(repository.merge_base(one.0, two.0)?))
+  }
+
+  async fn internal_merge_bases(&self, one: Oid, two: Oid) -> anyhow::Result<Vec<Oid>> {
+    let repository = self.repository.lock().await;
+    let bases = repository.merge_bases(one.0, two.0)?;
+
+    Ok(bases.iter().copied().map(Oid).collect())
+  }
+
+  async fn internal_merge_base_many(&self, oids: Vec<git2::Oid>) -> anyhow::Result<Oid> {
+    let repository = self.repository.lock().await;
+    Ok(Oid(repository.merge_base_many(&oids)?))
+  }
+
+  async fn internal_merge_base_octopus(&self, oids: Vec<git2::Oid>) -> anyhow::Result<Oid> {
+    let repository = self.repository.lock().await;
+    let Some((first, others)) = oids.split_first().filter(|(_, others)| !others.is_empty()) else {
+      bail!("at least two commits are required to find an ancestor");
+    };
+
+    // Same as libgit2: the base of all the commits is found one commit at a time.
+    let base = others
+      .iter()
+      .try_fold(*first, |base, oid| repository.merge_base(base, *oid))?;
+
+    Ok(Oid(base))
+  }
+
+  async fn internal_graph_descendant_of(&self, commit: Oid, ancestor: Oid) -> anyhow::Result<bool> {
+    let repository = self.repository.lock().await;
+    Ok(repository.graph_descendant_of(commit.0, ancestor.0)?)
+  }
+
+  async fn internal_graph_reachable_from_any(
+    &self,
+    commit: git2::Oid,
+    descendants: Vec<git2::Oid>,
+  ) -> anyhow::Result<bool> {
+    if descendants.is_empty() {
+      return Ok(false);
+    }
+
+    if descendants.contains(&commit) {
+      return Ok(true);
+    }
+
+    // The commit is reachable if it is the merge base of itself and the descendants.
+    let repository = self.repository.lock().await;
+    let oids = [vec![commit], descendants].concat();
+    match repository.merge_base_many(&oids) {
+      Ok(base) => Ok(base == commit),
+      Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(false),
+      Err(e) => Err(e.into()),
+    }
+  }
+
+  async fn internal_create_rev_walk(&self) -> anyhow::Result<RevwalkHandle> {
+    let repository = self.repository.lock().await;
+    let revwalk = repository.revwalk()?;
+
+    Ok(RevwalkHandle::new(revwalk))
+  }
+
   pub(crate) async fn internal_find_tree(&self, target: Oid) -> anyhow::Result<Tree> {
     let repository = self.repository.lock().await;
     let tree = repository.find_tree(target.0)?;
@@ -57,6 +159,27 @@ impl Repository {
     Ok(Tree::from(tree))
   }
 
+  async fn internal_find_annotated_commit(&self, target: Oid) -> anyhow::Result<AnnotatedCommit> {
+    let repository = self.repository.lock().await;
+    let commit = repository.find_annotated_commit(target.0)?;
+
+    Ok(AnnotatedCommit::new(commit, None))
+  }
+
+  async fn internal_reference_to_annotated_commit(
+    &self,
+    name: String,
+  ) -> anyhow::Result<AnnotatedCommit> {
+    let repository = self.repository.lock().await;
+    let reference = repository.find_reference(&name)?;
+    let commit = repository.reference_to_annotated_commit(&reference)?;
+
+    Ok(AnnotatedCommit::new(
+      commit,
+      reference.name().map(ToString::to_string),
+    ))
+  }
+
   pub(crate) async fn internal_create_commit(
     &self,
     update_ref: Option<String>,
@@ -99,16 +222,20 @@ impl Repository {
 
     let mut rebase =
       repository.rebase(Some(&current_branch), None, Some(&annotated_commit), None)?;
-    loop {
-      let Some(op) = rebase.next() else {
-        break;
-      };
-      let _ = op?;
+    let committer: git2::Signature = committer.try_into()?;
+    let result = (|| {
+      while let Some(op) = rebase.next() {
+        op?;
+        rebase.commit(None, &committer, None)?;
+      }
 
-      rebase.commit(None, &committer.clone().try_into()?, None)?;
-    }
+      rebase.finish(None)
+    })();
 
-    rebase.finish(None)?;
+    if let Err(e) = result {
+      rebase.abort()?;
+      return Err(e.into());
+    }
 
     Ok(())
   }
@@ -263,6 +390,8 @@ impl Repository {
       .map_err(|e| e.into())
   }
 
+  /// Rebases HEAD onto the given reference, committing every operation as is.
+  /// The rebase is aborted if any of the commits does not apply cleanly.
   #[napi(ts_return_type = "Promise<void>")]
   pub async fn fast_rebase(&self, onto_ref: String) -> napi::Result<()> {
     self
@@ -298,6 +427,25 @@ impl Repository {
     Ok(promise)
   }
 
+  #[napi(ts_return_type = "Promise<Tag>")]
+  pub fn find_tag(
+    &self,
+    target: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oid = *target;
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_find_tag(oid).await {
+        Ok(tag) => deferred.resolve(|_| Ok(tag)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
   #[napi(ts_return_type = "Promise<Tree>")]
   pub fn find_tree(
     &self,
@@ -319,6 +467,53 @@ impl Repository {
     Ok(promise)
   }
 
+  #[napi(ts_return_type = "Promise<AnnotatedCommit>")]
+  pub fn find_annotated_commit(
+    &self,
+    target: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oid = *target;
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_find_annotated_commit(oid).await {
+        Ok(commit) => {
+          deferred.resolve(|_| Ok(commit));
+        }
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Creates an annotated commit from a reference, keeping track of the reference name
+  /// for merge and rebase messages.
+  #[napi(ts_return_type = "Promise<AnnotatedCommit>")]
+  pub fn reference_to_annotated_commit(
+    &self,
+    reference: ClassInstance<crate::reference::Reference>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let name = reference
+      .name()
+      .ok_or_else(|| anyhow::Error::msg("Invalid reference name"))?;
+
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_reference_to_annotated_commit(name).await {
+        Ok(commit) => {
+          deferred.resolve(|_| Ok(commit));
+        }
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
   #[napi(ts_return_type = "Promise<Remote>")]
   pub fn create_remote(
     &self,
@@ -372,6 +567,177 @@ impl Repository {
     Ok(promise)
   }
 
+  /// Lists the branches of the given type, defaulting to all of them.
+  #[napi(ts_return_type = "Promise<Array<Branch>>")]
+  pub fn branches(
+    &self,
+    branch_type: Option<BranchType>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<ListBranches> {
+    AsyncTask::new(ListBranches::new(
+      this,
+      branch_type.unwrap_or(BranchType::All),
+    ))
+  }
+
+  /// Computes, for every local branch, its upstream and how many commits it is ahead or behind.
+  #[napi(ts_return_type = "Promise<Array<BranchTracking>>")]
+  pub fn branch_tracking_summary(
+    &self,
+    this: Reference<Repository>,
+  ) -> AsyncTask<BranchTrackingSummary> {
+    AsyncTask::new(BranchTrackingSummary::new(this))
+  }
+
+  /// Counts the commits unique to local and to upstream.
+  #[napi(ts_return_type = "Promise<AheadBehind>")]
+  pub fn graph_ahead_behind(
+    &self,
+    local: ClassInstance<Oid>,
+    upstream: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let (local, upstream) = (*local, *upstream);
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_graph_ahead_behind(local, upstream).await {
+        Ok(ahead_behind) => deferred.resolve(move |_| Ok(ahead_behind)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Finds the best common ancestor of two commits.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn merge_base(
+    &self,
+    one: ClassInstance<Oid>,
+    two: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let (one, two) = (*one, *two);
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_merge_base(one, two).await {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Finds all the best common ancestors of two commits, e.g. after criss-cross merges.
+  #[napi(ts_return_type = "Promise<Array<Oid>>")]
+  pub fn merge_bases(
+    &self,
+    one: ClassInstance<Oid>,
+    two: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let (one, two) = (*one, *two);
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_merge_bases(one, two).await {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Finds the best common ancestor of the first commit and a hypothetical merge of all the others.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn merge_base_many(
+    &self,
+    oids: Vec<ClassInstance<Oid>>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oids = oids.iter().map(|oid| oid.0).collect();
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_merge_base_many(oids).await {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Finds the common ancestor of all the commits, as for an octopus merge.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn merge_base_octopus(
+    &self,
+    oids: Vec<ClassInstance<Oid>>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oids = oids.iter().map(|oid| oid.0).collect();
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_merge_base_octopus(oids).await {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Whether commit is a descendant of ancestor. A commit is not considered a descendant of itself.
+  #[napi(ts_return_type = "Promise<boolean>")]
+  pub fn graph_descendant_of(
+    &self,
+    commit: ClassInstance<Oid>,
+    ancestor: ClassInstance<Oid>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let (commit, ancestor) = (*commit, *ancestor);
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_graph_descendant_of(commit, ancestor).await {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
+  /// Whether commit is reachable from any of the descendants, or is one of them.
+  #[napi(ts_return_type = "Promise<boolean>")]
+  pub fn graph_reachable_from_any(
+    &self,
+    commit: ClassInstance<Oid>,
+    descendants: Vec<ClassInstance<Oid>>,
+    this: Reference<Repository>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let commit = commit.0;
+    let descendants = descendants.iter().map(|oid| oid.0).collect();
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this
+        .internal_graph_reachable_from_any(commit, descendants)
+        .await
+      {
+        Ok(result) => deferred.resolve(move |_| Ok(result)),
+        Err(e) => deferred.reject(e.into()),
+      };
+    });
+
+    Ok(promise)
+  }
+
   #[napi]
   pub fn create_branch(
     &self,
@@ -421,6 +787,23 @@ impl Repository {
     Ok(AsyncTask::new(GetBranchCommit::new(this, reference)))
   }
 
+  /// Describes HEAD like `git describe`, naming it after the closest reachable tag (e.g. `v1.0.0-2-gabc1234`).
+  /// The dirty suffix is appended when the working directory has local changes.
+  #[napi]
+  pub async fn describe(&self, options: Option<DescribeOptions>) -> Result<String> {
+    let options = options.unwrap_or_default();
+    let repository = self.repository.lock().await;
+    let describe = repository
+      .describe(&(&options).into())
+      .map_err(anyhow::Error::from)?;
+
+    Ok(
+      describe
+        .format(Some(&(&options).into()))
+        .map_err(anyhow::Error::from)?,
+    )
+  }
+
   #[napi]
   pub async fn index(&self) -> Result<Index> {
     let repository = self.repository.lock().await;
@@ -429,6 +812,507 @@ impl Repository {
     ))
   }
 
+  #[napi]
+  pub async fn statuses(&self, options: Option<StatusOptions>) -> Result<Vec<StatusEntry>> {
+    let repository = self.repository.lock().await;
+    let mut opts = git2::StatusOptions::from(options.unwrap_or_default());
+    let statuses = repository
+      .statuses(Some(&mut opts))
+      .map_err(anyhow::Error::from)?;
+
+    Ok(statuses.iter().map(StatusEntry::from).collect())
+  }
+
+  /// Diffs two trees. A missing tree is treated as empty.
+  #[napi(ts_return_type = "Promise<Diff>")]
+  pub fn diff_tree_to_tree(
+    &self,
+    old_tree: Option<ClassInstance<Tree>>,
+    new_tree: Option<ClassInstance<Tree>>,
+    options: Option<DiffOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateDiff> {
+    let target = DiffTarget::TreeToTree(
+      old_tree.map(|t| t.as_ref().clone()),
+      new_tree.map(|t| t.as_ref().clone()),
+    );
+
+    AsyncTask::new(CreateDiff::new(this, target, options.unwrap_or_default()))
+  }
+
+  /// Diffs a tree against an index, defaulting to the repository index.
+  #[napi(ts_return_type = "Promise<Diff>")]
+  pub fn diff_tree_to_index(
+    &self,
+    tree: Option<ClassInstance<Tree>>,
+    index: Option<Reference<Index>>,
+    options: Option<DiffOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateDiff> {
+    let target = DiffTarget::TreeToIndex(tree.map(|t| t.as_ref().clone()), index);
+    AsyncTask::new(CreateDiff::new(this, target, options.unwrap_or_default()))
+  }
+
+  /// Diffs an index against the working directory, defaulting to the repository index.
+  #[napi(ts_return_type = "Promise<Diff>")]
+  pub fn diff_index_to_workdir(
+    &self,
+    index: Option<Reference<Index>>,
+    options: Option<DiffOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateDiff> {
+    let target = DiffTarget::IndexToWorkdir(index);
+    AsyncTask::new(CreateDiff::new(this, target, options.unwrap_or_default()))
+  }
+
+  /// Diffs a tree against the working directory, using the index to account for staged changes
+  /// like `git diff <tree>` does.
+  #[napi(ts_return_type = "Promise<Diff>")]
+  pub fn diff_tree_to_workdir_with_index(
+    &self,
+    tree: Option<ClassInstance<Tree>>,
+    options: Option<DiffOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateDiff> {
+    let target = DiffTarget::TreeToWorkdirWithIndex(tree.map(|t| t.as_ref().clone()));
+    AsyncTask::new(CreateDiff::new(this, target, options.unwrap_or_default()))
+  }
+
+  /// Applies a diff to the working directory and/or the index.
+  /// Resolves to the paths the diff does not apply to: if there are any, nothing is written.
+  #[napi(ts_return_type = "Promise<Array<string>>")]
+  pub fn apply(
+    &self,
+    diff: Reference<Diff>,
+    location: ApplyLocation,
+    options: Option<ApplyOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<ApplyDiff> {
+    let check = options.unwrap_or_default().check.unwrap_or(false);
+    AsyncTask::new(ApplyDiff::new(this, diff, location, check))
+  }
+
+  /// Applies a diff to a tree, returning the resulting index without touching the repository.
+  #[napi(ts_return_type = "Promise<Index>")]
+  pub fn apply_to_tree(
+    &self,
+    tree: ClassInstance<Tree>,
+    diff: Reference<Diff>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<ApplyDiffToTree> {
+    AsyncTask::new(ApplyDiffToTree::new(this, tree.as_ref().clone(), diff))
+  }
+
+  /// Analyzes how the given commits could be merged into HEAD.
+  #[napi(ts_return_type = "Promise<MergeAnalysis>")]
+  pub fn merge_analysis(
+    &self,
+    their_heads: Vec<Reference<AnnotatedCommit>>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<AnalyzeMerge> {
+    AsyncTask::new(AnalyzeMerge::new(this, their_heads))
+  }
+
+  /// Merges the given commits into HEAD, updating the index and the working directory.
+  /// Conflicts are left in the index and the repository is left in the `Merge` state:
+  /// the merge commit has to be created by the caller.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn merge(
+    &self,
+    their_heads: Vec<Reference<AnnotatedCommit>>,
+    merge_options: Option<MergeOptions>,
+    checkout_options: Option<CheckoutOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<MergeAnnotatedCommits> {
+    AsyncTask::new(MergeAnnotatedCommits::new(
+      this,
+      their_heads,
+      merge_options.unwrap_or_default(),
+      checkout_options.unwrap_or_default(),
+    ))
+  }
+
+  /// Merges two commits in memory, returning the resulting index.
+  #[napi(ts_return_type = "Promise<Index>")]
+  pub fn merge_commits(
+    &self,
+    ours: ClassInstance<Commit>,
+    theirs: ClassInstance<Commit>,
+    options: Option<MergeOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<MergeCommits> {
+    AsyncTask::new(MergeCommits::new(
+      this,
+      ours.as_ref().clone(),
+      theirs.as_ref().clone(),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Merges two trees in memory given their common ancestor, returning the resulting index.
+  #[napi(ts_return_type = "Promise<Index>")]
+  pub fn merge_trees(
+    &self,
+    ancestor: ClassInstance<Tree>,
+    ours: ClassInstance<Tree>,
+    theirs: ClassInstance<Tree>,
+    options: Option<MergeOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<MergeTrees> {
+    AsyncTask::new(MergeTrees::new(
+      this,
+      ancestor.as_ref().clone(),
+      ours.as_ref().clone(),
+      theirs.as_ref().clone(),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Applies the changes introduced by the commit to the index and the working directory.
+  /// Conflicts are left in the index and the repository is left in the `CherryPick` state.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn cherrypick(
+    &self,
+    commit: ClassInstance<Commit>,
+    options: Option<CherrypickOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<PickCommit> {
+    AsyncTask::new(PickCommit::new(
+      this,
+      commit.as_ref().clone(),
+      PickAction::Cherrypick,
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Cherry-picks the commit onto another one in memory, returning the resulting index.
+  /// The mainline parent (starting from 1) is required when cherry-picking a merge commit.
+  #[napi(ts_return_type = "Promise<Index>")]
+  pub fn cherrypick_commit(
+    &self,
+    commit: ClassInstance<Commit>,
+    onto: ClassInstance<Commit>,
+    mainline: Option<u32>,
+    options: Option<MergeOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<PickCommitInMemory> {
+    AsyncTask::new(PickCommitInMemory::new(
+      this,
+      commit.as_ref().clone(),
+      onto.as_ref().clone(),
+      PickAction::Cherrypick,
+      mainline.unwrap_or(0),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Reverts the changes introduced by the commit in the index and the working directory.
+  /// Conflicts are left in the index and the repository is left in the `Revert` state.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn revert(
+    &self,
+    commit: ClassInstance<Commit>,
+    options: Option<CherrypickOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<PickCommit> {
+    AsyncTask::new(PickCommit::new(
+      this,
+      commit.as_ref().clone(),
+      PickAction::Revert,
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Reverts the commit on top of another one in memory, returning the resulting index.
+  /// The mainline parent (starting from 1) is required when reverting a merge commit.
+  #[napi(ts_return_type = "Promise<Index>")]
+  pub fn revert_commit(
+    &self,
+    commit: ClassInstance<Commit>,
+    onto: ClassInstance<Commit>,
+    mainline: Option<u32>,
+    options: Option<MergeOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<PickCommitInMemory> {
+    AsyncTask::new(PickCommitInMemory::new(
+      this,
+      commit.as_ref().clone(),
+      onto.as_ref().clone(),
+      PickAction::Revert,
+      mainline.unwrap_or(0),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Cherry-picks the commits in the given range (e.g. `main..feature`), oldest first.
+  /// The sequence pauses on the first conflict, leaving the repository in the `CherryPickSequence` state.
+  #[napi(ts_return_type = "Promise<Sequencer>")]
+  pub fn cherrypick_sequence(
+    &self,
+    range: String,
+    options: Option<CherrypickOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<StartSequence> {
+    AsyncTask::new(StartSequence::new(
+      this,
+      Some((SequencerAction::Pick, range)),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Reverts the commits in the given range (e.g. `v1.0..v1.1`), newest first.
+  /// The sequence pauses on the first conflict, leaving the repository in the `RevertSequence` state.
+  #[napi(ts_return_type = "Promise<Sequencer>")]
+  pub fn revert_sequence(
+    &self,
+    range: String,
+    options: Option<CherrypickOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<StartSequence> {
+    AsyncTask::new(StartSequence::new(
+      this,
+      Some((SequencerAction::Revert, range)),
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Resumes an interrupted cherry-pick or revert sequence from its on-disk state.
+  #[napi(ts_return_type = "Promise<Sequencer>")]
+  pub fn open_sequencer(
+    &self,
+    options: Option<CherrypickOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<StartSequence> {
+    AsyncTask::new(StartSequence::new(this, None, options.unwrap_or_default()))
+  }
+
+  /// Starts rebasing a branch. The operations are applied one at a time through the returned `Rebase`.
+  #[napi(ts_return_type = "Promise<Rebase>")]
+  pub fn rebase(
+    &self,
+    options: Option<RebaseOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<StartRebase> {
+    AsyncTask::new(StartRebase::new(this, options.unwrap_or_default(), false))
+  }
+
+  /// Reopens an interrupted rebase. Branch, upstream and onto options are ignored.
+  #[napi(ts_return_type = "Promise<Rebase>")]
+  pub fn open_rebase(
+    &self,
+    options: Option<RebaseOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<StartRebase> {
+    AsyncTask::new(StartRebase::new(this, options.unwrap_or_default(), true))
+  }
+
+  /// Rebases a branch without touching the index and the working directory, so it works on bare repositories too.
+  /// The branch reference is updated only if all the operations apply cleanly, otherwise
+  /// the rebase is aborted and the first conflicting operation is reported.
+  /// Commits are created by the given committer, defaulting to the repository signature.
+  #[napi(ts_return_type = "Promise<InMemoryRebaseResult>")]
+  pub fn rebase_in_memory(
+    &self,
+    options: RebaseOptions,
+    committer: Option<ClassInstance<Signature>>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<RebaseInMemory> {
+    AsyncTask::new(RebaseInMemory::new(
+      this,
+      options,
+      committer.map(|c| c.as_ref().clone()),
+    ))
+  }
+
+  /// Builds an interactive rebase plan picking the commits of the branch not reachable from upstream.
+  /// The todo list can be edited before running it with `runRebasePlan`.
+  #[napi(ts_return_type = "Promise<RebasePlan>")]
+  pub fn rebase_plan(
+    &self,
+    upstream: String,
+    options: Option<RebasePlanOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateRebasePlan> {
+    AsyncTask::new(CreateRebasePlan::new(
+      this,
+      upstream,
+      options.unwrap_or_default(),
+    ))
+  }
+
+  /// Executes a rebase plan in the working directory, starting or resuming it.
+  /// Resolves to the updated plan, paused on an edit or a conflicting step, or done.
+  #[napi(ts_return_type = "Promise<RebasePlan>")]
+  pub fn run_rebase_plan(
+    &self,
+    plan: RebasePlan,
+    this: Reference<Repository>,
+  ) -> AsyncTask<RunRebasePlan> {
+    AsyncTask::new(RunRebasePlan::new(this, plan, false))
+  }
+
+  /// Aborts a paused rebase plan, restoring the original branch.
+  #[napi(ts_return_type = "Promise<RebasePlan>")]
+  pub fn abort_rebase_plan(
+    &self,
+    plan: RebasePlan,
+    this: Reference<Repository>,
+  ) -> AsyncTask<RunRebasePlan> {
+    AsyncTask::new(RunRebasePlan::new(this, plan, true))
+  }
+
+  /// Creates an annotated tag object pointing to target and the `refs/tags/<name>` reference.
+  /// If force is true, an existing tag with the same name is overwritten.
+  /// Resolves to the id of the tag object.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn create_tag(
+    &self,
+    name: String,
+    target: ClassInstance<Oid>,
+    tagger: ClassInstance<Signature>,
+    message: String,
+    force: bool,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateTag> {
+    AsyncTask::new(CreateTag::new(
+      this,
+      name,
+      *target,
+      Some((tagger.as_ref().clone(), message)),
+      force,
+    ))
+  }
+
+  /// Creates a `refs/tags/<name>` reference pointing directly to target.
+  /// If force is true, an existing tag with the same name is overwritten.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn create_lightweight_tag(
+    &self,
+    name: String,
+    target: ClassInstance<Oid>,
+    force: bool,
+    this: Reference<Repository>,
+  ) -> AsyncTask<CreateTag> {
+    AsyncTask::new(CreateTag::new(this, name, *target, None, force))
+  }
+
+  /// Lists the tag names, optionally filtered by a glob pattern (e.g. `v1.*`).
+  #[napi]
+  pub async fn tag_names(&self, pattern: Option<String>) -> Result<Vec<String>> {
+    let repository = self.repository.lock().await;
+    let names = repository
+      .tag_names(pattern.as_deref())
+      .map_err(anyhow::Error::from)?;
+
+    Ok(names.iter().flatten().map(ToString::to_string).collect())
+  }
+
+  /// Deletes the `refs/tags/<name>` reference. The tag object is left in the object database.
+  #[napi]
+  pub async fn delete_tag(&self, name: String) -> Result<()> {
+    let repository = self.repository.lock().await;
+    repository.tag_delete(&name).map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Saves the local changes to a new stash, resetting the working directory to HEAD.
+  #[napi(ts_return_type = "Promise<Oid>")]
+  pub fn stash_save(
+    &self,
+    stasher: ClassInstance<Signature>,
+    message: Option<String>,
+    flags: Option<StashFlags>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<SaveStash> {
+    AsyncTask::new(SaveStash::new(
+      this,
+      stasher.as_ref().clone(),
+      message,
+      flags.unwrap_or_default(),
+    ))
+  }
+
+  /// Lists the stashed states, the most recent first.
+  #[napi]
+  pub async fn stash_list(&self) -> Result<Vec<StashEntry>> {
+    let mut repository = self.repository.lock().await;
+    let mut entries = vec![];
+    repository
+      .stash_foreach(|index, message, oid| {
+        entries.push(StashEntry {
+          index: index as u32,
+          message: message.to_string(),
+          oid: Oid(*oid),
+        });
+        true
+      })
+      .map_err(anyhow::Error::from)?;
+
+    Ok(entries)
+  }
+
+  /// Applies a stashed state to the working directory, keeping it in the stash list.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn stash_apply(
+    &self,
+    index: u32,
+    options: Option<StashApplyOptions>,
+    env: Env,
+    this: Reference<Repository>,
+  ) -> Result<AsyncTask<ApplyStash>> {
+    let opts = options.unwrap_or_default().into_stash_apply_opts(&env)?;
+
+    Ok(AsyncTask::new(ApplyStash::new(
+      this,
+      index as usize,
+      opts,
+      false,
+    )))
+  }
+
+  /// Applies a stashed state to the working directory, removing it from the stash list if successful.
+  #[napi(ts_return_type = "Promise<void>")]
+  pub fn stash_pop(
+    &self,
+    index: u32,
+    options: Option<StashApplyOptions>,
+    env: Env,
+    this: Reference<Repository>,
+  ) -> Result<AsyncTask<ApplyStash>> {
+    let opts = options.unwrap_or_default().into_stash_apply_opts(&env)?;
+
+    Ok(AsyncTask::new(ApplyStash::new(
+      this,
+      index as usize,
+      opts,
+      true,
+    )))
+  }
+
+  /// Removes a stashed state from the stash list.
+  #[napi]
+  pub async fn stash_drop(&self, index: u32) -> Result<()> {
+    let mut repository = self.repository.lock().await;
+    repository
+      .stash_drop(index as usize)
+      .map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Computes the blame of a file, as a list of hunks sorted by line.
+  #[napi(ts_return_type = "Promise<Array<BlameHunk>>")]
+  pub fn blame(
+    &self,
+    path: String,
+    options: Option<BlameOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<BlameFile> {
+    let options = git2::BlameOptions::from(options.unwrap_or_default());
+    AsyncTask::new(BlameFile::new(this, path, options))
+  }
+
   #[napi]
   pub async fn signature(&self) -> Result<Signature> {
     let repository = self.repository.lock().await;
@@ -547,6 +1431,40 @@ impl Repository {
     Ok(promise)
   }
 
+  /// Parses a revision spec like `HEAD~3`, `main..feature` or `main...feature`.
+  #[napi]
+  pub async fn revparse(&self, spec: String) -> Result<Revspec> {
+    let repository = self.repository.lock().await;
+    let revspec = repository.revparse(&spec).map_err(anyhow::Error::from)?;
+
+    Ok(Revspec::from(revspec))
+  }
+
+  /// Finds the object of a single revision spec, e.g. `HEAD~3`, `main@{upstream}`, `v1.2^{tree}` or a short id.
+  #[napi]
+  pub async fn revparse_single(&self, spec: String) -> Result<Object> {
+    let repository = self.repository.lock().await;
+    let object = repository
+      .revparse_single(&spec)
+      .map_err(anyhow::Error::from)?;
+
+    Ok(Object::from(object))
+  }
+
+  /// Finds the object of a single revision spec and the reference it was resolved through, if any.
+  #[napi]
+  pub async fn revparse_ext(&self, spec: String) -> Result<RevparseExt> {
+    let repository = self.repository.lock().await;
+    let (object, reference) = repository
+      .revparse_ext(&spec)
+      .map_err(anyhow::Error::from)?;
+
+    Ok(RevparseExt {
+      object: Object::from(object),
+      reference: reference.map(crate::reference::Reference::new),
+    })
+  }
+
   #[napi]
   pub async fn get_reference(&self, reference: String) -> Result<crate::reference::Reference> {
     let repository = self.repository.lock().await;
@@ -591,11 +1509,29 @@ impl Repository {
     Ok(refs)
   }
 
-  #[napi]
-  pub async fn create_rev_walk(&self) -> napi::Result<Revwalk> {
-    let repository = self.repository.lock().await;
-    let rev_walk = repository.revwalk().map_err(anyhow::Error::from)?;
+  /// Lists the commits matching the options, newest first, like `git log`.
+  #[napi(ts_return_type = "Promise<Array<CommitSummary>>")]
+  pub fn log(
+    &self,
+    options: Option<LogOptions>,
+    this: Reference<Repository>,
+  ) -> AsyncTask<QueryLog> {
+    AsyncTask::new(QueryLog::new(this, options.unwrap_or_default()))
+  }
 
-    Ok(rev_walk.into())
+  /// Creates a revwalk, which is also iterable with `for await` over the walked oids.
+  #[napi(ts_return_type = "Promise<Revwalk & AsyncIterable<Oid>>")]
+  pub fn create_rev_walk(&self, this: Reference<Repository>, env: Env) -> Result<JsObject> {
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      match this.internal_create_rev_walk().await {
+        Ok(handle) => {
+          deferred.resolve(move |env| Revwalk::new(handle, this.clone(env)?).into_iterable(env))
+        }
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
   }
 }
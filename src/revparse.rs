@@ -0,0 +1,36 @@
+use crate::object::Object;
+use crate::reference::Reference;
+
+/// The result of `Repository.revparse`.
+#[napi(object, object_from_js = false)]
+pub struct Revspec {
+  /// The left side of a range, or the only object of a single revision.
+  pub from: Option<Object>,
+  /// The right side of a range.
+  pub to: Option<Object>,
+  /// The spec is a range, e.g. `main..feature`.
+  pub range: bool,
+  /// The spec is a symmetric difference, e.g. `main...feature`.
+  pub merge_base: bool,
+}
+
+impl From<git2::Revspec<'_>> for Revspec {
+  fn from(value: git2::Revspec<'_>) -> Self {
+    let mode = value.mode();
+
+    Self {
+      range: mode.contains(git2::RevparseMode::RANGE),
+      merge_base: mode.contains(git2::RevparseMode::MERGE_BASE),
+      from: value.from().cloned().map(Object::from),
+      to: value.to().cloned().map(Object::from),
+    }
+  }
+}
+
+/// The result of `Repository.revparseExt`.
+#[napi(object, object_from_js = false)]
+pub struct RevparseExt {
+  pub object: Object,
+  /// The reference the spec was resolved through, e.g. `refs/heads/main` for `main~2`.
+  pub reference: Option<Reference>,
+}
@@ -1,24 +1,47 @@
+use crate::commit::CommitSummary;
 use crate::object::Oid;
+use crate::repository::Repository;
 use crate::Sort;
 use napi::bindgen_prelude::*;
 use napi::tokio::sync::Mutex;
-use napi::JsObject;
+use napi::{JsFunction, JsObject, JsSymbol};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The number of commits fetched at once by the async iterators.
+const ITERATOR_BATCH_SIZE: usize = 256;
+
+/// A git2 revwalk which can be moved to the JS main thread to be wrapped.
+pub struct RevwalkHandle(git2::Revwalk<'static>);
+
+unsafe impl Send for RevwalkHandle {}
+
+impl RevwalkHandle {
+  pub(crate) fn new(revwalk: git2::Revwalk<'_>) -> Self {
+    Self(unsafe { std::mem::transmute(revwalk) })
+  }
+}
 
 #[napi]
 pub struct Revwalk {
   inner: Mutex<git2::Revwalk<'static>>,
+  pathspecs: Mutex<Vec<String>>,
+  /// Whether the walk only follows first parents, to be honoured by the pathspecs filter.
+  /// Like the libgit2 flag, it is cleared on reset and once the walk is over.
+  first_parent: AtomicBool,
+  repository: Reference<Repository>,
 }
 
-impl From<git2::Revwalk<'_>> for Revwalk {
-  fn from(value: git2::Revwalk<'_>) -> Self {
-    Self::new(value)
-  }
-}
+unsafe impl Send for Revwalk {}
+unsafe impl Sync for Revwalk {}
 
 impl Revwalk {
-  pub(crate) fn new(revwalk: git2::Revwalk<'_>) -> Self {
+  pub(crate) fn new(handle: RevwalkHandle, repository: Reference<Repository>) -> Self {
     Self {
-      inner: Mutex::new(unsafe { std::mem::transmute(revwalk) }),
+      inner: Mutex::new(handle.0),
+      pathspecs: Mutex::new(vec![]),
+      first_parent: AtomicBool::new(false),
+      repository,
     }
   }
 }
@@ -60,21 +83,128 @@ impl Revwalk {
     Ok(promise)
   }
 
+  /// Marks a commit and its ancestors as uninteresting: they will not be yielded by the walk.
   #[napi]
-  pub async fn next(&self) -> Result<Option<Oid>> {
+  pub fn hide(
+    &self,
+    oid: ClassInstance<Oid>,
+    this: Reference<Revwalk>,
+    env: Env,
+  ) -> Result<JsObject> {
+    let oid = oid.0;
+
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      let mut inner = this.inner.lock().await;
+      match inner.hide(oid).map_err(anyhow::Error::from) {
+        Ok(()) => deferred.resolve(|_| Ok(())),
+        Err(e) => deferred.reject(e.into()),
+      }
+    });
+
+    Ok(promise)
+  }
+
+  /// Pushes the references matching the glob, e.g. `tags/*`. Globs without a `refs/` prefix are relative to `refs/`.
+  #[napi]
+  pub async fn push_glob(&self, glob: String) -> Result<()> {
     let mut inner = self.inner.lock().await;
-    let o = inner.next();
-    match o {
-      Some(Ok(r)) => Ok(Some(Oid(r))),
-      Some(Err(e)) => Err(anyhow::Error::from(e).into()),
-      None => Ok(None),
-    }
+    inner.push_glob(&glob).map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Hides the references matching the glob, e.g. `remotes/origin/*`.
+  #[napi]
+  pub async fn hide_glob(&self, glob: String) -> Result<()> {
+    let mut inner = self.inner.lock().await;
+    inner.hide_glob(&glob).map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Pushes the commit pointed to by the reference with the given full name, e.g. `refs/heads/main`.
+  #[napi]
+  pub async fn push_ref(&self, reference: String) -> Result<()> {
+    let mut inner = self.inner.lock().await;
+    inner.push_ref(&reference).map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Hides the commit pointed to by the reference with the given full name.
+  #[napi]
+  pub async fn hide_ref(&self, reference: String) -> Result<()> {
+    let mut inner = self.inner.lock().await;
+    inner.hide_ref(&reference).map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  #[napi]
+  pub async fn push_head(&self) -> Result<()> {
+    let mut inner = self.inner.lock().await;
+    inner.push_head().map_err(anyhow::Error::from)?;
+
+    Ok(())
+  }
+
+  /// Follows only the first parent of merge commits, like `git log --first-parent`.
+  #[napi]
+  pub async fn simplify_first_parent(&self) -> Result<()> {
+    let mut inner = self.inner.lock().await;
+    inner.simplify_first_parent().map_err(anyhow::Error::from)?;
+    self.first_parent.store(true, Ordering::Relaxed);
+
+    Ok(())
+  }
+
+  /// Only yields the commits changing files matching the pathspecs, like `git log -- <path>...`.
+  /// Merge commits are yielded only if they differ from all their parents (from the first one
+  /// when following first parents only). An empty list disables the filter.
+  #[napi]
+  pub async fn set_pathspecs(&self, pathspecs: Vec<String>) -> Result<()> {
+    *self.pathspecs.lock().await = pathspecs;
+    Ok(())
+  }
+
+  #[napi]
+  pub async fn next(&self) -> Result<Option<Oid>> {
+    let mut oids = self.next_oids(1).await?;
+    Ok(oids.pop().map(Oid))
+  }
+
+  /// Returns up to count oids at once. An empty array means the walk is over.
+  #[napi]
+  pub async fn next_batch(&self, count: u32) -> Result<Vec<Oid>> {
+    let oids = self.next_oids(count as usize).await?;
+    Ok(oids.into_iter().map(Oid).collect())
+  }
+
+  /// Returns the summaries of up to count commits at once. An empty array means the walk is over.
+  #[napi]
+  pub async fn next_summary_batch(&self, count: u32) -> Result<Vec<CommitSummary>> {
+    Ok(self.next_summaries(count as usize).await?)
+  }
+
+  /// An async iterable over the oids of the walked commits, for `for await` loops.
+  /// Also used when walking with `for await (const oid of revwalk)`.
+  #[napi(ts_return_type = "AsyncIterableIterator<Oid>")]
+  pub fn oids(&self, this: Reference<Revwalk>, env: Env) -> Result<JsObject> {
+    RevwalkIterator::new(this, false).into_iterable(env)
+  }
+
+  /// An async iterable over the summaries of the walked commits, for `for await` loops.
+  #[napi(ts_return_type = "AsyncIterableIterator<CommitSummary>")]
+  pub fn summaries(&self, this: Reference<Revwalk>, env: Env) -> Result<JsObject> {
+    RevwalkIterator::new(this, true).into_iterable(env)
   }
 
   #[napi]
   pub async fn reset(&self) -> Result<()> {
     let mut inner = self.inner.lock().await;
     inner.reset().map_err(anyhow::Error::from)?;
+    self.first_parent.store(false, Ordering::Relaxed);
 
     Ok(())
   }
@@ -93,3 +223,184 @@ impl Revwalk {
     Ok(())
   }
 }
+
+impl Revwalk {
+  /// Walks the commits matching the pathspecs until count of them are collected.
+  pub(crate) async fn next_oids(&self, count: usize) -> anyhow::Result<Vec<git2::Oid>> {
+    let repository = self.repository.repository.lock().await;
+    let pathspecs = self.pathspecs.lock().await;
+    let mut inner = self.inner.lock().await;
+
+    self.walk(&repository, &mut inner, &pathspecs, count)
+  }
+
+  /// Walks up to count commits like `next_oids`, then looks up their summaries.
+  pub(crate) async fn next_summaries(&self, count: usize) -> anyhow::Result<Vec<CommitSummary>> {
+    let repository = self.repository.repository.lock().await;
+    let pathspecs = self.pathspecs.lock().await;
+    let mut inner = self.inner.lock().await;
+
+    self
+      .walk(&repository, &mut inner, &pathspecs, count)?
+      .into_iter()
+      .map(|oid| Ok(CommitSummary::from(&repository.find_commit(oid)?)))
+      .collect()
+  }
+
+  /// Advances the walk. The repository must be locked, as walking reads its object database.
+  fn walk(
+    &self,
+    repository: &git2::Repository,
+    inner: &mut git2::Revwalk,
+    pathspecs: &[String],
+    count: usize,
+  ) -> anyhow::Result<Vec<git2::Oid>> {
+    let mut oids = vec![];
+    while oids.len() < count {
+      let Some(oid) = inner.next().transpose()? else {
+        self.first_parent.store(false, Ordering::Relaxed);
+        break;
+      };
+
+      if !pathspecs.is_empty() {
+        let commit = repository.find_commit(oid)?;
+        let first_parent = self.first_parent.load(Ordering::Relaxed);
+        if !touches_paths(repository, &commit, pathspecs, first_parent)? {
+          continue;
+        }
+      }
+
+      oids.push(oid);
+    }
+
+    Ok(oids)
+  }
+
+  /// Wraps the revwalk in a JS object iterable with `for await`, yielding the oids.
+  pub(crate) fn into_iterable(self, env: Env) -> Result<JsObject> {
+    let mut object = self.into_instance(env)?.as_object(env);
+    let iterator = env.create_function_from_closure("[Symbol.asyncIterator]", |ctx| {
+      let this = ctx.this::<JsObject>()?;
+      let oids = this.get_named_property::<JsFunction>("oids")?;
+      oids.call_without_args(Some(&this))
+    })?;
+
+    object.set_property(async_iterator_symbol(env)?, iterator)?;
+    Ok(object)
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct RevwalkIteratorResult {
+  pub done: bool,
+  pub value: Option<Either<Oid, CommitSummary>>,
+}
+
+/// An async iterator over the commits of a revwalk, fetching them in batches.
+#[napi]
+pub struct RevwalkIterator {
+  revwalk: Reference<Revwalk>,
+  summaries: bool,
+  buffer: Mutex<VecDeque<Either<Oid, CommitSummary>>>,
+}
+
+impl RevwalkIterator {
+  fn new(revwalk: Reference<Revwalk>, summaries: bool) -> Self {
+    Self {
+      revwalk,
+      summaries,
+      buffer: Mutex::new(VecDeque::new()),
+    }
+  }
+
+  /// Wraps the iterator in a JS object which is also an async iterable, returning itself.
+  fn into_iterable(self, env: Env) -> Result<JsObject> {
+    let mut object = self.into_instance(env)?.as_object(env);
+    let iterator = env.create_function_from_closure("[Symbol.asyncIterator]", |ctx| {
+      Ok(ctx.this_unchecked::<JsObject>())
+    })?;
+
+    object.set_property(async_iterator_symbol(env)?, iterator)?;
+    Ok(object)
+  }
+
+  async fn internal_next(&self) -> anyhow::Result<Option<Either<Oid, CommitSummary>>> {
+    let mut buffer = self.buffer.lock().await;
+    if buffer.is_empty() {
+      if self.summaries {
+        let summaries = self.revwalk.next_summaries(ITERATOR_BATCH_SIZE).await?;
+        buffer.extend(summaries.into_iter().map(Either::B));
+      } else {
+        let oids = self.revwalk.next_oids(ITERATOR_BATCH_SIZE).await?;
+        buffer.extend(oids.into_iter().map(|oid| Either::A(Oid(oid))));
+      }
+    }
+
+    Ok(buffer.pop_front())
+  }
+}
+
+#[napi]
+impl RevwalkIterator {
+  #[napi(ts_return_type = "Promise<RevwalkIteratorResult>")]
+  pub fn next(&self, this: Reference<RevwalkIterator>, env: Env) -> Result<JsObject> {
+    let (deferred, promise) = env.create_deferred()?;
+    napi::tokio::spawn(async move {
+      let value = this.internal_next().await;
+
+      // Settles on the JS main thread, where the iterator reference has to be released.
+      deferred.resolve(move |_| {
+        drop(this);
+        let value = value?;
+        Ok(RevwalkIteratorResult {
+          done: value.is_none(),
+          value,
+        })
+      });
+    });
+
+    Ok(promise)
+  }
+}
+
+fn async_iterator_symbol(env: Env) -> Result<JsSymbol> {
+  let symbol = env
+    .get_global()?
+    .get_named_property_unchecked::<JsObject>("Symbol")?;
+
+  symbol.get_named_property_unchecked::<JsSymbol>("asyncIterator")
+}
+
+/// Whether the commit changes files matching the pathspecs, compared to each one of its parents,
+/// or to the first one only if first_parent is set.
+pub(crate) fn touches_paths(
+  repository: &git2::Repository,
+  commit: &git2::Commit,
+  pathspecs: &[String],
+  first_parent: bool,
+) -> anyhow::Result<bool> {
+  let mut opts = git2::DiffOptions::new();
+  for pathspec in pathspecs {
+    opts.pathspec(pathspec);
+  }
+
+  let tree = commit.tree()?;
+  if commit.parent_count() == 0 {
+    let diff = repository.diff_tree_to_tree(None, Some(&tree), Some(&mut opts))?;
+    return Ok(diff.deltas().len() > 0);
+  }
+
+  let parents = if first_parent {
+    1
+  } else {
+    commit.parent_count()
+  };
+  for parent in commit.parents().take(parents) {
+    let diff = repository.diff_tree_to_tree(Some(&parent.tree()?), Some(&tree), Some(&mut opts))?;
+    if diff.deltas().len() == 0 {
+      return Ok(false);
+    }
+  }
+
+  Ok(true)
+}
@@ -0,0 +1,360 @@
+use crate::cherrypick::CherrypickOptions;
+use crate::object::Oid;
+use crate::repository::Repository;
+use anyhow::{anyhow, bail};
+use napi::bindgen_prelude::*;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+#[napi]
+pub enum SequencerAction {
+  Pick,
+  Revert,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct SequencerStep {
+  pub action: SequencerAction,
+  pub id: Oid,
+  pub summary: String,
+}
+
+struct TodoEntry {
+  action: SequencerAction,
+  id: git2::Oid,
+  summary: String,
+}
+
+impl From<TodoEntry> for SequencerStep {
+  fn from(value: TodoEntry) -> Self {
+    Self {
+      action: value.action,
+      id: Oid(value.id),
+      summary: value.summary,
+    }
+  }
+}
+
+/// Cherry-picks or reverts a range of commits one at a time, pausing on conflicts.
+/// The state is stored on disk in the format used by git, so an interrupted sequence
+/// can be resumed with `Repository.openSequencer` or by git itself.
+#[napi]
+pub struct Sequencer {
+  repository: Reference<Repository>,
+  options: CherrypickOptions,
+}
+
+impl Sequencer {
+  pub(crate) fn new(repository: Reference<Repository>, options: CherrypickOptions) -> Self {
+    Self {
+      repository,
+      options,
+    }
+  }
+}
+
+#[napi]
+impl Sequencer {
+  /// The commits still to be applied, starting from the one the sequence is paused on.
+  #[napi]
+  pub async fn todo(&self) -> Result<Vec<SequencerStep>> {
+    let repository = self.repository.repository.lock().await;
+    let todo = read_todo(&repository)?;
+
+    Ok(todo.into_iter().map(SequencerStep::from).collect())
+  }
+
+  /// The step the sequence is paused on, or null once all the commits have been applied.
+  #[napi]
+  pub async fn current(&self) -> Result<Option<SequencerStep>> {
+    let repository = self.repository.repository.lock().await;
+    let todo = read_todo(&repository)?;
+
+    Ok(todo.into_iter().next().map(SequencerStep::from))
+  }
+
+  /// Commits the resolved changes of the current step and applies the remaining ones.
+  /// Resolves to the step the sequence paused on, or null once all the commits have been applied.
+  #[napi(js_name = "continue")]
+  pub async fn resume(&self) -> Result<Option<SequencerStep>> {
+    let repository = self.repository.repository.lock().await;
+    commit_current(&repository)?;
+
+    Ok(run(&repository, &self.options)?)
+  }
+
+  /// Discards the changes of the current step and applies the remaining ones.
+  #[napi]
+  pub async fn skip(&self) -> Result<Option<SequencerStep>> {
+    let repository = self.repository.repository.lock().await;
+    skip_current(&repository)?;
+
+    Ok(run(&repository, &self.options)?)
+  }
+
+  /// Stops the sequence, resetting HEAD, the index and the working directory to their original state.
+  #[napi]
+  pub async fn abort(&self) -> Result<()> {
+    let repository = self.repository.repository.lock().await;
+    Ok(abort(&repository)?)
+  }
+}
+
+fn sequencer_dir(repository: &git2::Repository) -> PathBuf {
+  repository.path().join("sequencer")
+}
+
+pub(crate) fn is_in_progress(repository: &git2::Repository) -> bool {
+  sequencer_dir(repository).join("todo").is_file()
+}
+
+fn read_oid(path: &Path) -> anyhow::Result<git2::Oid> {
+  Ok(git2::Oid::from_str(fs::read_to_string(path)?.trim())?)
+}
+
+fn read_todo(repository: &git2::Repository) -> anyhow::Result<Vec<TodoEntry>> {
+  let path = sequencer_dir(repository).join("todo");
+  let content = match fs::read_to_string(path) {
+    Ok(content) => content,
+    Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+    Err(e) => return Err(e.into()),
+  };
+
+  content
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    .map(|line| {
+      let mut parts = line.splitn(3, ' ');
+      let action = match parts.next() {
+        Some("pick" | "p") => SequencerAction::Pick,
+        Some("revert") => SequencerAction::Revert,
+        _ => bail!("Invalid sequencer instruction: {}", line),
+      };
+
+      let id = parts
+        .next()
+        .ok_or_else(|| anyhow!("Invalid sequencer instruction: {}", line))?;
+      let id = repository.revparse_single(id)?.peel_to_commit()?.id();
+
+      Ok(TodoEntry {
+        action,
+        id,
+        summary: parts.next().unwrap_or_default().to_string(),
+      })
+    })
+    .collect()
+}
+
+fn write_todo(repository: &git2::Repository, todo: &[TodoEntry]) -> anyhow::Result<()> {
+  let content = todo
+    .iter()
+    .map(|entry| {
+      let action = match entry.action {
+        SequencerAction::Pick => "pick",
+        SequencerAction::Revert => "revert",
+      };
+
+      format!("{} {} {}\n", action, entry.id, entry.summary)
+    })
+    .collect::<String>();
+
+  fs::write(sequencer_dir(repository).join("todo"), content)?;
+  Ok(())
+}
+
+fn read_mainline(repository: &git2::Repository) -> u32 {
+  git2::Config::open(&sequencer_dir(repository).join("opts"))
+    .and_then(|config| config.get_i32("options.mainline"))
+    .map(|mainline| mainline.max(0) as u32)
+    .unwrap_or(0)
+}
+
+fn head_commit(repository: &git2::Repository) -> anyhow::Result<git2::Commit<'_>> {
+  Ok(repository.head()?.peel_to_commit()?)
+}
+
+/// Removes the state files of the current step, keeping the sequencer state.
+pub(crate) fn cleanup_step(repository: &git2::Repository) -> anyhow::Result<()> {
+  for file in ["CHERRY_PICK_HEAD", "REVERT_HEAD", "MERGE_MSG"] {
+    match fs::remove_file(repository.path().join(file)) {
+      Err(e) if e.kind() != ErrorKind::NotFound => return Err(e.into()),
+      _ => {}
+    }
+  }
+
+  Ok(())
+}
+
+/// Writes the sequencer state for the given range, to be applied with `run`.
+pub(crate) fn start(
+  repository: &git2::Repository,
+  action: SequencerAction,
+  range: &str,
+  options: &CherrypickOptions,
+) -> anyhow::Result<()> {
+  if repository.state() != git2::RepositoryState::Clean || is_in_progress(repository) {
+    bail!("Another operation is in progress");
+  }
+
+  // Like git, commits are picked oldest first and reverted newest first.
+  let mut revwalk = repository.revwalk()?;
+  revwalk.set_sorting(match action {
+    SequencerAction::Pick => git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE,
+    SequencerAction::Revert => git2::Sort::TOPOLOGICAL,
+  })?;
+  revwalk.push_range(range)?;
+
+  let todo = revwalk
+    .map(|oid| {
+      let commit = repository.find_commit(oid?)?;
+      Ok(TodoEntry {
+        action,
+        id: commit.id(),
+        summary: commit.summary().unwrap_or_default().to_string(),
+      })
+    })
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  if todo.is_empty() {
+    bail!("No commits in range {}", range);
+  }
+
+  let head = head_commit(repository)?.id();
+  let dir = sequencer_dir(repository);
+  fs::create_dir_all(&dir)?;
+  fs::write(dir.join("head"), format!("{}\n", head))?;
+  fs::write(dir.join("abort-safety"), format!("{}\n", head))?;
+  if let Some(mainline) = options.mainline {
+    fs::write(
+      dir.join("opts"),
+      format!("[options]\n\tmainline = {}\n", mainline),
+    )?;
+  }
+
+  write_todo(repository, &todo)
+}
+
+/// Applies the pending steps, stopping at the first one leaving conflicts in the index.
+pub(crate) fn run(
+  repository: &git2::Repository,
+  options: &CherrypickOptions,
+) -> anyhow::Result<Option<SequencerStep>> {
+  loop {
+    let mut todo = read_todo(repository)?;
+    if todo.is_empty() {
+      repository.cleanup_state()?;
+      return Ok(None);
+    }
+
+    let commit = repository.find_commit(todo[0].id)?;
+    let mainline = options
+      .mainline
+      .unwrap_or_else(|| read_mainline(repository));
+
+    match todo[0].action {
+      SequencerAction::Pick => {
+        let mut opts = git2::CherrypickOptions::from(options);
+        repository.cherrypick(&commit, Some(opts.mainline(mainline)))?
+      }
+      SequencerAction::Revert => {
+        let mut opts = git2::RevertOptions::from(options);
+        repository.revert(&commit, Some(opts.mainline(mainline)))?
+      }
+    }
+
+    if repository.index()?.has_conflicts() {
+      return Ok(Some(todo.swap_remove(0).into()));
+    }
+
+    commit_current(repository)?;
+  }
+}
+
+/// Commits the changes of the current step and removes it from the todo list.
+/// Steps whose changes are already present in HEAD do not produce a commit.
+fn commit_current(repository: &git2::Repository) -> anyhow::Result<()> {
+  let mut todo = read_todo(repository)?;
+  if todo.is_empty() {
+    bail!("No sequence in progress");
+  }
+
+  let mut index = repository.index()?;
+  if index.has_conflicts() {
+    bail!("Conflicts must be resolved before continuing");
+  }
+
+  let entry = todo.remove(0);
+  let head = head_commit(repository)?;
+  let tree = repository.find_tree(index.write_tree()?)?;
+
+  if tree.id() != head.tree_id() {
+    let original = repository.find_commit(entry.id)?;
+    let committer = repository.signature()?;
+    let (author, message) = match entry.action {
+      SequencerAction::Pick => (
+        original.author(),
+        original.message().unwrap_or_default().to_string(),
+      ),
+      SequencerAction::Revert => (
+        committer.clone(),
+        format!(
+          "Revert \"{}\"\n\nThis reverts commit {}.\n",
+          original.summary().unwrap_or_default(),
+          original.id()
+        ),
+      ),
+    };
+
+    let oid = repository.commit(Some("HEAD"), &author, &committer, &message, &tree, &[&head])?;
+    fs::write(
+      sequencer_dir(repository).join("abort-safety"),
+      format!("{}\n", oid),
+    )?;
+  }
+
+  cleanup_step(repository)?;
+  write_todo(repository, &todo)
+}
+
+/// Resets the changes of the current step and removes it from the todo list.
+fn skip_current(repository: &git2::Repository) -> anyhow::Result<()> {
+  let mut todo = read_todo(repository)?;
+  if todo.is_empty() {
+    bail!("No sequence in progress");
+  }
+
+  // A hard reset would also clean up the sequencer state.
+  let head = head_commit(repository)?;
+  let mut index = repository.index()?;
+  index.read_tree(&head.tree()?)?;
+  index.write()?;
+  repository.checkout_index(
+    Some(&mut index),
+    Some(git2::build::CheckoutBuilder::new().force()),
+  )?;
+
+  todo.remove(0);
+  cleanup_step(repository)?;
+  write_todo(repository, &todo)
+}
+
+/// Resets HEAD, the index and the working directory to their state before the sequence started.
+pub(crate) fn abort(repository: &git2::Repository) -> anyhow::Result<()> {
+  if !is_in_progress(repository) {
+    bail!("No sequence in progress");
+  }
+
+  let dir = sequencer_dir(repository);
+  let head = head_commit(repository)?.id();
+  if head != read_oid(&dir.join("abort-safety"))? {
+    bail!("HEAD has moved since the sequence was paused, refusing to reset it");
+  }
+
+  let original = repository.find_commit(read_oid(&dir.join("head"))?)?;
+  repository.reset(original.as_object(), git2::ResetType::Hard, None)?;
+  repository.cleanup_state()?;
+
+  Ok(())
+}
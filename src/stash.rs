@@ -0,0 +1,133 @@
+use crate::checkout::CheckoutOptions;
+use crate::js_callback::JsCallback;
+use crate::object::Oid;
+use crate::progress::ProgressReporter;
+use napi::threadsafe_function::ThreadSafeCallContext;
+use napi::{Env, JsFunction};
+
+#[napi(object)]
+#[derive(Default)]
+pub struct StashFlags {
+  /// Leave the changes already added to the index in place.
+  pub keep_index: Option<bool>,
+  /// Stash the untracked files too, removing them from the working directory.
+  pub include_untracked: Option<bool>,
+  /// Stash the ignored files too, removing them from the working directory.
+  pub include_ignored: Option<bool>,
+}
+
+impl From<&StashFlags> for git2::StashFlags {
+  fn from(value: &StashFlags) -> Self {
+    let mut flags = git2::StashFlags::DEFAULT;
+    flags.set(
+      git2::StashFlags::KEEP_INDEX,
+      value.keep_index.unwrap_or(false),
+    );
+    flags.set(
+      git2::StashFlags::INCLUDE_UNTRACKED,
+      value.include_untracked.unwrap_or(false),
+    );
+    flags.set(
+      git2::StashFlags::INCLUDE_IGNORED,
+      value.include_ignored.unwrap_or(false),
+    );
+
+    flags
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct StashEntry {
+  /// The position in the stash list, 0 being the most recent.
+  pub index: u32,
+  pub message: String,
+  pub oid: Oid,
+}
+
+#[napi]
+pub enum StashApplyProgress {
+  None,
+  LoadingStash,
+  AnalyzeIndex,
+  AnalyzeModified,
+  AnalyzeUntracked,
+  CheckoutUntracked,
+  CheckoutModified,
+  Done,
+}
+
+impl From<git2::StashApplyProgress> for StashApplyProgress {
+  fn from(value: git2::StashApplyProgress) -> Self {
+    match value {
+      git2::StashApplyProgress::None => StashApplyProgress::None,
+      git2::StashApplyProgress::LoadingStash => StashApplyProgress::LoadingStash,
+      git2::StashApplyProgress::AnalyzeIndex => StashApplyProgress::AnalyzeIndex,
+      git2::StashApplyProgress::AnalyzeModified => StashApplyProgress::AnalyzeModified,
+      git2::StashApplyProgress::AnalyzeUntracked => StashApplyProgress::AnalyzeUntracked,
+      git2::StashApplyProgress::CheckoutUntracked => StashApplyProgress::CheckoutUntracked,
+      git2::StashApplyProgress::CheckoutModified => StashApplyProgress::CheckoutModified,
+      git2::StashApplyProgress::Done => StashApplyProgress::Done,
+    }
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct StashApplyOptions {
+  /// Restore the changes added to the index too, not only the ones of the working directory.
+  pub reinstate_index: Option<bool>,
+  pub checkout_options: Option<CheckoutOptions>,
+  #[napi(ts_type = "(progress: StashApplyProgress) => void")]
+  pub progress: Option<JsFunction>,
+}
+
+impl StashApplyOptions {
+  pub fn into_stash_apply_opts(self, env: &Env) -> napi::Result<StashApplyOpts> {
+    Ok(StashApplyOpts {
+      reinstate_index: self.reinstate_index.unwrap_or(false),
+      checkout_options: self.checkout_options.unwrap_or_default(),
+      progress: self
+        .progress
+        .map(|f| {
+          JsCallback::new(env, f, |ctx: ThreadSafeCallContext<StashApplyProgress>| {
+            Ok(vec![ctx.value])
+          })
+        })
+        .transpose()?,
+    })
+  }
+}
+
+pub struct StashApplyOpts {
+  reinstate_index: bool,
+  checkout_options: CheckoutOptions,
+  progress: Option<JsCallback<StashApplyProgress>>,
+}
+
+impl StashApplyOpts {
+  /// Configures the git2 stash apply options. The progress callback is invoked
+  /// through a threadsafe function, so they must be used off the JS main thread.
+  /// git2 keeps a pointer to the options for the progress callback: they must not be moved afterwards.
+  pub fn configure(&self, opts: &mut git2::StashApplyOptions<'static>) {
+    opts.checkout_options(self.checkout_options.to_builder());
+    if self.reinstate_index {
+      opts.reinstantiate_index();
+    }
+
+    if let Some(progress) = self.progress.clone() {
+      let mut reporter = ProgressReporter::new(progress);
+      opts.progress_cb(move |progress| {
+        let done = matches!(progress, git2::StashApplyProgress::Done);
+        reporter.report(progress.into(), done)
+      });
+    }
+  }
+
+  /// Reports the error thrown by the progress callback instead of the generic libgit2 one.
+  pub fn map_error(&self, error: anyhow::Error) -> napi::Error {
+    match self.progress.as_ref().and_then(|cb| cb.error()) {
+      Some(message) => napi::Error::from_reason(message),
+      None => error.into(),
+    }
+  }
+}
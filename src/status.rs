@@ -0,0 +1,95 @@
+/// Status bits of a file, to be tested against `StatusEntry.status`.
+#[napi]
+#[allow(dead_code)]
+pub enum FileStatus {
+  Current = 0,
+  IndexNew = 1,
+  IndexModified = 2,
+  IndexDeleted = 4,
+  IndexRenamed = 8,
+  IndexTypechange = 16,
+  WtNew = 128,
+  WtModified = 256,
+  WtDeleted = 512,
+  WtTypechange = 1024,
+  WtRenamed = 2048,
+  WtUnreadable = 4096,
+  Ignored = 16384,
+  Conflicted = 32768,
+}
+
+/// Options for `Repository.statuses`. Defaults match `git status`.
+#[napi(object)]
+#[derive(Default)]
+pub struct StatusOptions {
+  pub include_untracked: Option<bool>,
+  pub include_ignored: Option<bool>,
+  pub recurse_untracked_dirs: Option<bool>,
+  pub pathspecs: Option<Vec<String>>,
+  pub renames_head_to_index: Option<bool>,
+  pub renames_index_to_workdir: Option<bool>,
+}
+
+impl From<StatusOptions> for git2::StatusOptions {
+  fn from(value: StatusOptions) -> Self {
+    let mut opts = git2::StatusOptions::new();
+    opts
+      .include_untracked(value.include_untracked.unwrap_or(true))
+      .include_ignored(value.include_ignored.unwrap_or(false))
+      .recurse_untracked_dirs(value.recurse_untracked_dirs.unwrap_or(false))
+      .renames_head_to_index(value.renames_head_to_index.unwrap_or(false))
+      .renames_index_to_workdir(value.renames_index_to_workdir.unwrap_or(false));
+
+    for pathspec in value.pathspecs.unwrap_or_default() {
+      opts.pathspec(pathspec);
+    }
+
+    opts
+  }
+}
+
+#[napi(object)]
+pub struct StatusRename {
+  pub old_path: Option<String>,
+  pub new_path: Option<String>,
+}
+
+impl StatusRename {
+  fn from_delta(delta: Option<git2::DiffDelta<'_>>) -> Option<Self> {
+    let delta = delta?;
+    if delta.status() != git2::Delta::Renamed {
+      return None;
+    }
+
+    Some(Self {
+      old_path: delta
+        .old_file()
+        .path()
+        .map(|p| p.to_string_lossy().to_string()),
+      new_path: delta
+        .new_file()
+        .path()
+        .map(|p| p.to_string_lossy().to_string()),
+    })
+  }
+}
+
+#[napi(object)]
+pub struct StatusEntry {
+  pub path: String,
+  /// Combination of `FileStatus` bits.
+  pub status: u32,
+  pub head_to_index_rename: Option<StatusRename>,
+  pub index_to_workdir_rename: Option<StatusRename>,
+}
+
+impl From<git2::StatusEntry<'_>> for StatusEntry {
+  fn from(value: git2::StatusEntry<'_>) -> Self {
+    Self {
+      path: String::from_utf8_lossy(value.path_bytes()).to_string(),
+      status: value.status().bits(),
+      head_to_index_rename: StatusRename::from_delta(value.head_to_index()),
+      index_to_workdir_rename: StatusRename::from_delta(value.index_to_workdir()),
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use crate::commit::Signature;
+use crate::object::{Object, ObjectType, Oid};
+use napi::bindgen_prelude::*;
+
+/// An annotated tag object.
+#[napi]
+#[derive(Clone)]
+pub struct Tag {
+  inner: git2::Tag<'static>,
+}
+
+unsafe impl Send for Tag {}
+
+impl From<git2::Tag<'_>> for Tag {
+  fn from(value: git2::Tag) -> Self {
+    Self {
+      inner: unsafe { std::mem::transmute(value) },
+    }
+  }
+}
+
+#[napi]
+impl Tag {
+  #[napi]
+  pub fn oid(&self) -> Oid {
+    Oid(self.inner.id())
+  }
+
+  #[napi]
+  pub fn name(&self) -> Option<String> {
+    self.inner.name().map(ToString::to_string)
+  }
+
+  #[napi]
+  pub fn message(&self) -> Option<String> {
+    self.inner.message().map(ToString::to_string)
+  }
+
+  /// The tagger of the tag, or null if the tag object has no tagger.
+  #[napi]
+  pub fn tagger(&self) -> Option<Signature> {
+    self.inner.tagger().map(Signature::from)
+  }
+
+  /// The id of the object the tag points to.
+  #[napi]
+  pub fn target_id(&self) -> Oid {
+    Oid(self.inner.target_id())
+  }
+
+  #[napi]
+  pub fn target_type(&self) -> Option<ObjectType> {
+    self.inner.target_type().map(ObjectType::from)
+  }
+
+  /// Recursively peels the tag until a non-tag object is found.
+  #[napi]
+  pub fn peel(&self) -> Result<Object> {
+    Ok(
+      self
+        .inner
+        .peel()
+        .map(Object::from)
+        .map_err(anyhow::Error::from)?,
+    )
+  }
+}
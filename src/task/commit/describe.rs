@@ -0,0 +1,42 @@
+use crate::describe::DescribeOptions;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct DescribeCommit {
+  repository: Reference<Repository>,
+  oid: git2::Oid,
+  options: DescribeOptions,
+}
+
+impl DescribeCommit {
+  pub fn new(repository: Reference<Repository>, oid: git2::Oid, options: DescribeOptions) -> Self {
+    Self {
+      repository,
+      oid,
+      options,
+    }
+  }
+
+  fn describe(&self) -> anyhow::Result<String> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let commit = repository.find_commit(self.oid)?;
+    let describe = commit.as_object().describe(&(&self.options).into())?;
+
+    Ok(describe.format(Some(&(&self.options).into()))?)
+  }
+}
+
+#[napi]
+impl Task for DescribeCommit {
+  type Output = String;
+  type JsValue = String;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.describe()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
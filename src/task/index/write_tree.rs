@@ -0,0 +1,36 @@
+use crate::index::Index;
+use crate::object::Oid;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct WriteTreeTo {
+  index: Reference<Index>,
+  repository: Reference<Repository>,
+}
+
+impl WriteTreeTo {
+  pub fn new(index: Reference<Index>, repository: Reference<Repository>) -> Self {
+    Self { index, repository }
+  }
+}
+
+#[napi]
+impl Task for WriteTreeTo {
+  type Output = git2::Oid;
+  type JsValue = Oid;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut index = futures::executor::block_on(self.index.inner.lock());
+    Ok(
+      index
+        .write_tree_to(&repository)
+        .map_err(anyhow::Error::from)?,
+    )
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(Oid(value))
+  }
+}
@@ -1,12 +1,28 @@
+mod commit;
+mod index;
 mod remote;
 mod repository;
 
+pub use commit::describe::DescribeCommit;
+pub use index::write_tree::WriteTreeTo;
 pub use remote::connect::ConnectRemote;
 pub use remote::pull::PullRemote;
 pub use remote::push::PushRemote;
+pub use repository::apply::{ApplyDiff, ApplyDiffToTree};
+pub use repository::blame::BlameFile;
+pub use repository::branches::{BranchTrackingSummary, ListBranches};
+pub use repository::cherrypick::{PickAction, PickCommit, PickCommitInMemory};
 pub use repository::clone::CloneRepository;
 pub use repository::create_commit::CreateCommit;
+pub use repository::diff::{CreateDiff, DiffTarget};
 pub use repository::fetch::FetchRepository;
 pub use repository::get_branch_commit::{BranchNameRef, GetBranchCommit};
 pub use repository::init::InitRepository;
+pub use repository::log::QueryLog;
+pub use repository::merge::{AnalyzeMerge, MergeAnnotatedCommits, MergeCommits, MergeTrees};
 pub use repository::open::OpenRepository;
+pub use repository::rebase::{RebaseInMemory, StartRebase};
+pub use repository::rebase_plan::{CreateRebasePlan, RunRebasePlan};
+pub use repository::sequencer::StartSequence;
+pub use repository::stash::{ApplyStash, SaveStash};
+pub use repository::tag::CreateTag;
@@ -1,24 +1,24 @@
-use crate::remote::Remote;
+use crate::remote::{Remote, RemoteCallbacksOpts};
 use crate::Direction;
 use napi::bindgen_prelude::Reference;
-use napi::{Env, Ref, Task};
+use napi::{Env, Task};
 
 pub struct ConnectRemote {
   remote: Reference<Remote>,
   direction: Direction,
-  credentials_callback: Option<Ref<()>>,
+  callbacks: RemoteCallbacksOpts,
 }
 
 impl ConnectRemote {
   pub fn new(
     remote: Reference<Remote>,
     direction: Direction,
-    credentials_callback: Option<Ref<()>>,
+    callbacks: RemoteCallbacksOpts,
   ) -> Self {
     Self {
       remote,
       direction,
-      credentials_callback,
+      callbacks,
     }
   }
 }
@@ -29,24 +29,25 @@ impl Task for ConnectRemote {
   type JsValue = ();
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    Ok(())
-  }
-
-  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
     let dir = match self.direction {
       Direction::Fetch => git2::Direction::Fetch,
       Direction::Push => git2::Direction::Push,
     };
 
-    let cb = Remote::prepare_remote_callbacks(
-      self
-        .credentials_callback
-        .as_ref()
-        .and_then(|c| env.get_reference_value(c).ok()),
-      &env,
-    )?;
-    self.remote.internal_connect(dir, cb)?;
+    self.callbacks.abort.check()?;
 
+    let cb = Remote::prepare_remote_callbacks(self.callbacks.clone());
+    self
+      .remote
+      .internal_connect(dir, cb)
+      .map_err(|e| self.callbacks.map_error(e))
+  }
+
+  fn resolve(&mut self, _: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
     Ok(())
   }
+
+  fn finally(&mut self, env: Env) -> napi::Result<()> {
+    self.callbacks.abort.release(env)
+  }
 }
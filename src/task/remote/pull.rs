@@ -1,23 +1,23 @@
-use crate::remote::Remote;
+use crate::remote::{Remote, RemoteCallbacksOpts};
 use napi::bindgen_prelude::Reference;
-use napi::{Env, Ref, Task};
+use napi::{Env, Task};
 
 pub struct PullRemote {
   remote: Reference<Remote>,
   ref_specs: Vec<String>,
-  credentials_callback: Option<Ref<()>>,
+  callbacks: RemoteCallbacksOpts,
 }
 
 impl PullRemote {
   pub fn new(
     remote: Reference<Remote>,
     ref_specs: Vec<String>,
-    credentials_callback: Option<Ref<()>>,
+    callbacks: RemoteCallbacksOpts,
   ) -> Self {
     Self {
       remote,
       ref_specs,
-      credentials_callback,
+      callbacks,
     }
   }
 }
@@ -28,29 +28,22 @@ impl Task for PullRemote {
   type JsValue = ();
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    Ok(())
-  }
+    self.callbacks.abort.check()?;
 
-  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
-    let cb = Remote::prepare_remote_callbacks(
-      self
-        .credentials_callback
-        .as_ref()
-        .and_then(|c| env.get_reference_value(c).ok()),
-      &env,
-    )?;
-    let ut_cb = Remote::prepare_remote_callbacks(
-      self
-        .credentials_callback
-        .as_ref()
-        .and_then(|c| env.get_reference_value(c).ok()),
-      &env,
-    )?;
+    let cb = Remote::prepare_remote_callbacks(self.callbacks.clone());
+    let ut_cb = Remote::prepare_remote_callbacks(self.callbacks.clone());
 
     self
       .remote
-      .internal_pull(self.ref_specs.as_slice(), cb, ut_cb)?;
+      .internal_pull(self.ref_specs.as_slice(), cb, ut_cb)
+      .map_err(|e| self.callbacks.map_error(e))
+  }
 
+  fn resolve(&mut self, _: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
     Ok(())
   }
+
+  fn finally(&mut self, env: Env) -> napi::Result<()> {
+    self.callbacks.abort.release(env)
+  }
 }
@@ -1,47 +1,47 @@
-use crate::remote::Remote;
+use crate::remote::{PushResult, Remote, RemoteCallbacksOpts};
 use napi::bindgen_prelude::Reference;
-use napi::{Env, Ref, Task};
+use napi::{Env, Task};
 
 pub struct PushRemote {
   remote: Reference<Remote>,
   ref_specs: Vec<String>,
-  credentials_callback: Option<Ref<()>>,
+  callbacks: RemoteCallbacksOpts,
 }
 
 impl PushRemote {
   pub fn new(
     remote: Reference<Remote>,
     ref_specs: Vec<String>,
-    credentials_callback: Option<Ref<()>>,
+    callbacks: RemoteCallbacksOpts,
   ) -> Self {
     Self {
       remote,
       ref_specs,
-      credentials_callback,
+      callbacks,
     }
   }
 }
 
 #[napi]
 impl Task for PushRemote {
-  type Output = ();
-  type JsValue = ();
+  type Output = Vec<PushResult>;
+  type JsValue = Vec<PushResult>;
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    Ok(())
-  }
+    self.callbacks.abort.check()?;
 
-  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
-    let cb = Remote::prepare_remote_callbacks(
-      self
-        .credentials_callback
-        .as_ref()
-        .and_then(|c| env.get_reference_value(c).ok()),
-      &env,
-    )?;
+    let cb = Remote::prepare_remote_callbacks(self.callbacks.clone());
+    self
+      .remote
+      .internal_push(self.ref_specs.as_slice(), cb)
+      .map_err(|e| self.callbacks.map_error(e))
+  }
 
-    self.remote.internal_push(self.ref_specs.as_slice(), cb)?;
+  fn resolve(&mut self, _: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
 
-    Ok(())
+  fn finally(&mut self, env: Env) -> napi::Result<()> {
+    self.callbacks.abort.release(env)
   }
 }
@@ -0,0 +1,142 @@
+use crate::diff::{ApplyLocation, Diff};
+use crate::index::Index;
+use crate::repository::Repository;
+use crate::tree::Tree;
+use anyhow::anyhow;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+/// Checks every delta on its own to find out which files the diff does not apply to.
+fn conflicting_paths<F>(diff: &git2::Diff<'_>, mut apply: F) -> anyhow::Result<Vec<String>>
+where
+  F: FnMut(&mut git2::ApplyOptions<'_>) -> Result<(), git2::Error>,
+{
+  let mut conflicts = vec![];
+  for (idx, delta) in diff.deltas().enumerate() {
+    let mut current = 0;
+    let mut opts = git2::ApplyOptions::new();
+    opts.check(true).delta_callback(move |_| {
+      current += 1;
+      current - 1 == idx
+    });
+
+    match apply(&mut opts) {
+      Ok(()) => {}
+      Err(e) if e.code() == git2::ErrorCode::ApplyFail => {
+        let file = delta.new_file().path().or(delta.old_file().path());
+        conflicts.push(
+          file
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        );
+      }
+      Err(e) => return Err(e.into()),
+    }
+  }
+
+  Ok(conflicts)
+}
+
+pub struct ApplyDiff {
+  repository: Reference<Repository>,
+  diff: Reference<Diff>,
+  location: ApplyLocation,
+  check: bool,
+}
+
+impl ApplyDiff {
+  pub fn new(
+    repository: Reference<Repository>,
+    diff: Reference<Diff>,
+    location: ApplyLocation,
+    check: bool,
+  ) -> Self {
+    Self {
+      repository,
+      diff,
+      location,
+      check,
+    }
+  }
+
+  fn apply(&self) -> anyhow::Result<Vec<String>> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let diff = futures::executor::block_on(self.diff.inner.lock());
+    let location = git2::ApplyLocation::from(self.location);
+
+    let mut opts = git2::ApplyOptions::new();
+    opts.check(self.check);
+
+    match repository.apply(&diff, location, Some(&mut opts)) {
+      Ok(()) => Ok(vec![]),
+      Err(e) if e.code() == git2::ErrorCode::ApplyFail => {
+        conflicting_paths(&diff, |opts| repository.apply(&diff, location, Some(opts)))
+      }
+      Err(e) => Err(e.into()),
+    }
+  }
+}
+
+#[napi]
+impl Task for ApplyDiff {
+  type Output = Vec<String>;
+  type JsValue = Vec<String>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.apply()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct ApplyDiffToTree {
+  repository: Reference<Repository>,
+  tree: Tree,
+  diff: Reference<Diff>,
+}
+
+impl ApplyDiffToTree {
+  pub fn new(repository: Reference<Repository>, tree: Tree, diff: Reference<Diff>) -> Self {
+    Self {
+      repository,
+      tree,
+      diff,
+    }
+  }
+
+  fn apply(&self) -> anyhow::Result<Index> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let diff = futures::executor::block_on(self.diff.inner.lock());
+    let tree = git2::Tree::from(self.tree.clone());
+
+    match repository.apply_to_tree(&tree, &diff, None) {
+      Ok(index) => Ok(Index::from(index)),
+      Err(e) if e.code() == git2::ErrorCode::ApplyFail => {
+        let conflicts = conflicting_paths(&diff, |opts| {
+          repository
+            .apply_to_tree(&tree, &diff, Some(opts))
+            .map(|_| ())
+        })?;
+
+        Err(anyhow!("patch does not apply to {}", conflicts.join(", ")))
+      }
+      Err(e) => Err(e.into()),
+    }
+  }
+}
+
+#[napi]
+impl Task for ApplyDiffToTree {
+  type Output = Index;
+  type JsValue = Index;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.apply()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
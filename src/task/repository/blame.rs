@@ -0,0 +1,42 @@
+use crate::blame::BlameHunk;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+use std::path::Path;
+
+pub struct BlameFile {
+  repository: Reference<Repository>,
+  path: String,
+  options: git2::BlameOptions,
+}
+
+impl BlameFile {
+  pub fn new(repository: Reference<Repository>, path: String, options: git2::BlameOptions) -> Self {
+    Self {
+      repository,
+      path,
+      options,
+    }
+  }
+
+  fn blame(&mut self) -> anyhow::Result<Vec<BlameHunk>> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let blame = repository.blame_file(Path::new(&self.path), Some(&mut self.options))?;
+
+    Ok(blame.iter().map(BlameHunk::from).collect())
+  }
+}
+
+#[napi]
+impl Task for BlameFile {
+  type Output = Vec<BlameHunk>;
+  type JsValue = Vec<BlameHunk>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.blame()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
@@ -0,0 +1,125 @@
+use crate::branch::{Branch, BranchHandle, BranchTracking, BranchType};
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct ListBranches {
+  repository: Reference<Repository>,
+  branch_type: BranchType,
+}
+
+impl ListBranches {
+  pub fn new(repository: Reference<Repository>, branch_type: BranchType) -> Self {
+    Self {
+      repository,
+      branch_type,
+    }
+  }
+
+  fn list(&self) -> anyhow::Result<Vec<BranchHandle>> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let branches = repository
+      .branches(self.branch_type.into())?
+      .map(|b| b.map(|(branch, _)| BranchHandle::new(branch)))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(branches)
+  }
+}
+
+#[napi]
+impl Task for ListBranches {
+  type Output = Vec<BranchHandle>;
+  type JsValue = Vec<Branch>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.list()?)
+  }
+
+  fn resolve(&mut self, env: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    value
+      .into_iter()
+      .map(|b| Ok(Branch::new(b, self.repository.clone(env)?)))
+      .collect()
+  }
+}
+
+pub struct BranchTrackingSummary {
+  repository: Reference<Repository>,
+}
+
+impl BranchTrackingSummary {
+  pub fn new(repository: Reference<Repository>) -> Self {
+    Self { repository }
+  }
+
+  fn summary(&self) -> anyhow::Result<Vec<BranchTracking>> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut summary = vec![];
+
+    for branch in repository.branches(Some(git2::BranchType::Local))? {
+      let (branch, _) = branch?;
+      let reference = branch.get();
+      let (Some(refname), Some(name)) = (reference.name(), branch.name()?) else {
+        continue;
+      };
+
+      let mut tracking = BranchTracking {
+        name: name.to_string(),
+        upstream: None,
+        gone: false,
+        ahead: 0,
+        behind: 0,
+      };
+
+      // Resolved from the `branch.<name>.remote` and `branch.<name>.merge` configuration.
+      let upstream = match repository.branch_upstream_name(refname) {
+        Ok(upstream) => upstream,
+        Err(e) if e.code() == git2::ErrorCode::NotFound => {
+          summary.push(tracking);
+          continue;
+        }
+        Err(e) => return Err(e.into()),
+      };
+
+      let upstream = upstream
+        .as_str()
+        .ok_or_else(|| anyhow::Error::msg("Upstream name is not valid UTF-8"))?;
+      tracking.upstream = Some(upstream.to_string());
+
+      let upstream_oid = match repository.refname_to_id(upstream) {
+        Ok(oid) => oid,
+        Err(e) if e.code() == git2::ErrorCode::NotFound => {
+          tracking.gone = true;
+          summary.push(tracking);
+          continue;
+        }
+        Err(e) => return Err(e.into()),
+      };
+
+      if let Some(local) = reference.target() {
+        let (ahead, behind) = repository.graph_ahead_behind(local, upstream_oid)?;
+        tracking.ahead = ahead as u32;
+        tracking.behind = behind as u32;
+      }
+
+      summary.push(tracking);
+    }
+
+    Ok(summary)
+  }
+}
+
+#[napi]
+impl Task for BranchTrackingSummary {
+  type Output = Vec<BranchTracking>;
+  type JsValue = Vec<BranchTracking>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.summary()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
@@ -0,0 +1,125 @@
+use crate::cherrypick::CherrypickOptions;
+use crate::commit::Commit;
+use crate::index::Index;
+use crate::merge::MergeOptions;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+#[derive(Clone, Copy)]
+pub enum PickAction {
+  Cherrypick,
+  Revert,
+}
+
+pub struct PickCommit {
+  repository: Reference<Repository>,
+  commit: Commit,
+  action: PickAction,
+  options: CherrypickOptions,
+}
+
+impl PickCommit {
+  pub fn new(
+    repository: Reference<Repository>,
+    commit: Commit,
+    action: PickAction,
+    options: CherrypickOptions,
+  ) -> Self {
+    Self {
+      repository,
+      commit,
+      action,
+      options,
+    }
+  }
+
+  fn pick(&self) -> anyhow::Result<()> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let commit = self.commit.clone().into();
+
+    match self.action {
+      PickAction::Cherrypick => {
+        repository.cherrypick(&commit, Some(&mut (&self.options).into()))?
+      }
+      PickAction::Revert => repository.revert(&commit, Some(&mut (&self.options).into()))?,
+    }
+
+    Ok(())
+  }
+}
+
+#[napi]
+impl Task for PickCommit {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.pick()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct PickCommitInMemory {
+  repository: Reference<Repository>,
+  commit: Commit,
+  onto: Commit,
+  action: PickAction,
+  mainline: u32,
+  options: MergeOptions,
+}
+
+impl PickCommitInMemory {
+  pub fn new(
+    repository: Reference<Repository>,
+    commit: Commit,
+    onto: Commit,
+    action: PickAction,
+    mainline: u32,
+    options: MergeOptions,
+  ) -> Self {
+    Self {
+      repository,
+      commit,
+      onto,
+      action,
+      mainline,
+      options,
+    }
+  }
+
+  fn pick(&self) -> anyhow::Result<Index> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let commit = self.commit.clone().into();
+    let onto = self.onto.clone().into();
+    let options = (&self.options).into();
+
+    let index = match self.action {
+      PickAction::Cherrypick => {
+        repository.cherrypick_commit(&commit, &onto, self.mainline, Some(&options))?
+      }
+      PickAction::Revert => {
+        repository.revert_commit(&commit, &onto, self.mainline, Some(&options))?
+      }
+    };
+
+    Ok(Index::from(index))
+  }
+}
+
+#[napi]
+impl Task for PickCommitInMemory {
+  type Output = Index;
+  type JsValue = Index;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.pick()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
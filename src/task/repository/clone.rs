@@ -29,23 +29,28 @@ impl CloneRepository {
 }
 
 impl Task for CloneRepository {
-  type Output = ();
+  type Output = git2::Repository;
   type JsValue = Repository;
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    Ok(())
-  }
+    let callbacks = &self.fetch_options.callbacks;
+    callbacks.abort.check()?;
 
-  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
-    let repository = clone(
+    clone(
       &self.url,
       &self.directory,
       self.recursive,
       &self.fetch_options,
-      &env,
-    )?;
+    )
+    .map_err(|e| callbacks.map_error(e))
+  }
+
+  fn resolve(&mut self, _: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(Repository::from(output))
+  }
 
-    Ok(Repository::from(repository))
+  fn finally(&mut self, env: Env) -> napi::Result<()> {
+    self.fetch_options.callbacks.abort.release(env)
   }
 }
 
@@ -54,9 +59,8 @@ fn clone<P: AsRef<Path>>(
   directory: P,
   recursive: bool,
   fetch_options: &FetchOpts,
-  env: &Env,
 ) -> Result<git2::Repository> {
-  let fo = fetch_options.to_git_fetch_opts(env)?;
+  let fo = fetch_options.to_git_fetch_opts();
   let repository = RepoBuilder::new()
     .fetch_options(fo)
     .clone(url, directory.as_ref())?;
@@ -0,0 +1,82 @@
+use crate::diff::{Diff, DiffHandle, DiffOptions};
+use crate::index::Index;
+use crate::repository::Repository;
+use crate::tree::Tree;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub enum DiffTarget {
+  TreeToTree(Option<Tree>, Option<Tree>),
+  TreeToIndex(Option<Tree>, Option<Reference<Index>>),
+  IndexToWorkdir(Option<Reference<Index>>),
+  TreeToWorkdirWithIndex(Option<Tree>),
+}
+
+pub struct CreateDiff {
+  repository: Reference<Repository>,
+  target: DiffTarget,
+  options: DiffOptions,
+}
+
+impl CreateDiff {
+  pub fn new(repository: Reference<Repository>, target: DiffTarget, options: DiffOptions) -> Self {
+    Self {
+      repository,
+      target,
+      options,
+    }
+  }
+
+  fn diff(&self) -> anyhow::Result<DiffHandle> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut opts = git2::DiffOptions::from(&self.options);
+
+    let mut diff = match &self.target {
+      DiffTarget::TreeToTree(old_tree, new_tree) => repository.diff_tree_to_tree(
+        old_tree.clone().map(git2::Tree::from).as_ref(),
+        new_tree.clone().map(git2::Tree::from).as_ref(),
+        Some(&mut opts),
+      )?,
+      DiffTarget::TreeToIndex(tree, index) => {
+        let index = index
+          .as_ref()
+          .map(|i| futures::executor::block_on(i.inner.lock()));
+        repository.diff_tree_to_index(
+          tree.clone().map(git2::Tree::from).as_ref(),
+          index.as_deref(),
+          Some(&mut opts),
+        )?
+      }
+      DiffTarget::IndexToWorkdir(index) => {
+        let index = index
+          .as_ref()
+          .map(|i| futures::executor::block_on(i.inner.lock()));
+        repository.diff_index_to_workdir(index.as_deref(), Some(&mut opts))?
+      }
+      DiffTarget::TreeToWorkdirWithIndex(tree) => repository.diff_tree_to_workdir_with_index(
+        tree.clone().map(git2::Tree::from).as_ref(),
+        Some(&mut opts),
+      )?,
+    };
+
+    if self.options.find_renames.unwrap_or(false) {
+      diff.find_similar(None)?;
+    }
+
+    Ok(DiffHandle::new(diff))
+  }
+}
+
+#[napi]
+impl Task for CreateDiff {
+  type Output = DiffHandle;
+  type JsValue = Diff;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.diff()?)
+  }
+
+  fn resolve(&mut self, env: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(Diff::new(value, Some(self.repository.clone(env)?)))
+  }
+}
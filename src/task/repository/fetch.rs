@@ -24,21 +24,28 @@ impl Task for FetchRepository {
   type JsValue = ();
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
+    let callbacks = &self.fetch_options.callbacks;
+    callbacks.abort.check()?;
+
+    fetch(&self.repository, &self.fetch_options).map_err(|e| callbacks.map_error(e))
+  }
+
+  fn resolve(&mut self, _: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
     Ok(())
   }
 
-  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
-    Ok(fetch(&self.repository, &self.fetch_options, &env)?)
+  fn finally(&mut self, env: Env) -> napi::Result<()> {
+    self.fetch_options.callbacks.abort.release(env)
   }
 }
 
-fn fetch(repository: &Repository, fetch_options: &FetchOpts, env: &Env) -> Result<()> {
+fn fetch(repository: &Repository, fetch_options: &FetchOpts) -> Result<()> {
   let remote_name = fetch_options
     .remote
     .clone()
     .unwrap_or_else(|| "origin".to_string());
 
-  let mut fo = fetch_options.to_git_fetch_opts(env)?;
+  let mut fo = fetch_options.to_git_fetch_opts();
   repository.internal_fetch(remote_name, &mut fo, fetch_options.prune.unwrap_or(false))?;
 
   Ok(())
@@ -0,0 +1,34 @@
+use crate::commit::CommitSummary;
+use crate::log::LogOptions;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct QueryLog {
+  repository: Reference<Repository>,
+  options: LogOptions,
+}
+
+impl QueryLog {
+  pub fn new(repository: Reference<Repository>, options: LogOptions) -> Self {
+    Self {
+      repository,
+      options,
+    }
+  }
+}
+
+#[napi]
+impl Task for QueryLog {
+  type Output = Vec<CommitSummary>;
+  type JsValue = Vec<CommitSummary>;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    Ok(crate::log::log(&repository, &self.options)?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
@@ -0,0 +1,201 @@
+use crate::annotated_commit::AnnotatedCommit;
+use crate::checkout::CheckoutOptions;
+use crate::commit::Commit;
+use crate::index::Index;
+use crate::merge::{MergeAnalysis, MergeOptions};
+use crate::repository::Repository;
+use crate::tree::Tree;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct AnalyzeMerge {
+  repository: Reference<Repository>,
+  their_heads: Vec<Reference<AnnotatedCommit>>,
+}
+
+impl AnalyzeMerge {
+  pub fn new(
+    repository: Reference<Repository>,
+    their_heads: Vec<Reference<AnnotatedCommit>>,
+  ) -> Self {
+    Self {
+      repository,
+      their_heads,
+    }
+  }
+
+  fn analyze(&self) -> anyhow::Result<MergeAnalysis> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let their_heads = self
+      .their_heads
+      .iter()
+      .map(|c| c.inner())
+      .collect::<Vec<_>>();
+
+    Ok(repository.merge_analysis(&their_heads)?.into())
+  }
+}
+
+#[napi]
+impl Task for AnalyzeMerge {
+  type Output = MergeAnalysis;
+  type JsValue = MergeAnalysis;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.analyze()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct MergeAnnotatedCommits {
+  repository: Reference<Repository>,
+  their_heads: Vec<Reference<AnnotatedCommit>>,
+  merge_options: MergeOptions,
+  checkout_options: CheckoutOptions,
+}
+
+impl MergeAnnotatedCommits {
+  pub fn new(
+    repository: Reference<Repository>,
+    their_heads: Vec<Reference<AnnotatedCommit>>,
+    merge_options: MergeOptions,
+    checkout_options: CheckoutOptions,
+  ) -> Self {
+    Self {
+      repository,
+      their_heads,
+      merge_options,
+      checkout_options,
+    }
+  }
+
+  fn merge(&self) -> anyhow::Result<()> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let their_heads = self
+      .their_heads
+      .iter()
+      .map(|c| c.inner())
+      .collect::<Vec<_>>();
+
+    repository.merge(
+      &their_heads,
+      Some(&mut (&self.merge_options).into()),
+      Some(&mut self.checkout_options.to_builder()),
+    )?;
+
+    Ok(())
+  }
+}
+
+#[napi]
+impl Task for MergeAnnotatedCommits {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.merge()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct MergeCommits {
+  repository: Reference<Repository>,
+  ours: Commit,
+  theirs: Commit,
+  options: MergeOptions,
+}
+
+impl MergeCommits {
+  pub fn new(
+    repository: Reference<Repository>,
+    ours: Commit,
+    theirs: Commit,
+    options: MergeOptions,
+  ) -> Self {
+    Self {
+      repository,
+      ours,
+      theirs,
+      options,
+    }
+  }
+}
+
+#[napi]
+impl Task for MergeCommits {
+  type Output = Index;
+  type JsValue = Index;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let index = repository
+      .merge_commits(
+        &self.ours.clone().into(),
+        &self.theirs.clone().into(),
+        Some(&(&self.options).into()),
+      )
+      .map_err(anyhow::Error::from)?;
+
+    Ok(Index::from(index))
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct MergeTrees {
+  repository: Reference<Repository>,
+  ancestor: Tree,
+  ours: Tree,
+  theirs: Tree,
+  options: MergeOptions,
+}
+
+impl MergeTrees {
+  pub fn new(
+    repository: Reference<Repository>,
+    ancestor: Tree,
+    ours: Tree,
+    theirs: Tree,
+    options: MergeOptions,
+  ) -> Self {
+    Self {
+      repository,
+      ancestor,
+      ours,
+      theirs,
+      options,
+    }
+  }
+}
+
+#[napi]
+impl Task for MergeTrees {
+  type Output = Index;
+  type JsValue = Index;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let index = repository
+      .merge_trees(
+        &self.ancestor.clone().into(),
+        &self.ours.clone().into(),
+        &self.theirs.clone().into(),
+        Some(&(&self.options).into()),
+      )
+      .map_err(anyhow::Error::from)?;
+
+    Ok(Index::from(index))
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
@@ -1,6 +1,18 @@
+pub mod apply;
+pub mod blame;
+pub mod branches;
+pub mod cherrypick;
 pub mod clone;
 pub mod create_commit;
+pub mod diff;
 pub mod fetch;
 pub mod get_branch_commit;
 pub mod init;
+pub mod log;
+pub mod merge;
 pub mod open;
+pub mod rebase;
+pub mod rebase_plan;
+pub mod sequencer;
+pub mod stash;
+pub mod tag;
@@ -0,0 +1,197 @@
+use crate::commit::Signature;
+use crate::object::Oid;
+use crate::rebase::{InMemoryRebaseResult, Rebase, RebaseConflict, RebaseOperation, RebaseOptions};
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct StartedRebase(git2::Rebase<'static>);
+
+unsafe impl Send for StartedRebase {}
+
+pub struct StartRebase {
+  repository: Reference<Repository>,
+  options: RebaseOptions,
+  reopen: bool,
+}
+
+impl StartRebase {
+  pub fn new(repository: Reference<Repository>, options: RebaseOptions, reopen: bool) -> Self {
+    Self {
+      repository,
+      options,
+      reopen,
+    }
+  }
+
+  fn start(&self) -> anyhow::Result<StartedRebase> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut opts = self.options.to_git_rebase_opts();
+
+    let rebase = if self.reopen {
+      repository.open_rebase(Some(&mut opts))?
+    } else {
+      repository.rebase(
+        self.options.branch.as_ref().map(|c| c.inner()),
+        self.options.upstream.as_ref().map(|c| c.inner()),
+        self.options.onto.as_ref().map(|c| c.inner()),
+        Some(&mut opts),
+      )?
+    };
+
+    Ok(StartedRebase(unsafe { std::mem::transmute(rebase) }))
+  }
+}
+
+#[napi]
+impl Task for StartRebase {
+  type Output = StartedRebase;
+  type JsValue = Rebase;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.start()?)
+  }
+
+  fn resolve(&mut self, env: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(Rebase::new(
+      value.0,
+      self.repository.clone(env)?,
+      self.options.inmemory.unwrap_or(false),
+    ))
+  }
+}
+
+pub struct RebaseInMemory {
+  repository: Reference<Repository>,
+  options: RebaseOptions,
+  committer: Option<Signature>,
+}
+
+impl RebaseInMemory {
+  pub fn new(
+    repository: Reference<Repository>,
+    options: RebaseOptions,
+    committer: Option<Signature>,
+  ) -> Self {
+    Self {
+      repository,
+      options,
+      committer,
+    }
+  }
+
+  fn rebase(&self) -> anyhow::Result<InMemoryRebaseResult> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let committer = match &self.committer {
+      Some(committer) => git2::Signature::try_from(committer.clone())?,
+      None => repository.signature()?,
+    };
+
+    let branch = self.options.branch.as_ref().map(|c| c.inner());
+    let upstream = self.options.upstream.as_ref().map(|c| c.inner());
+    let onto = self.options.onto.as_ref().map(|c| c.inner());
+    let branch_ref = match &self.options.branch {
+      Some(branch) => branch
+        .refname_str()
+        .map(|name| (name.to_string(), branch.inner().id())),
+      None => {
+        let head = repository.head()?;
+        match (head.is_branch(), head.name(), head.target()) {
+          (true, Some(name), Some(target)) => Some((name.to_string(), target)),
+          _ => None,
+        }
+      }
+    };
+
+    let mut opts = self.options.to_git_rebase_opts();
+    opts.inmemory(true);
+
+    let mut rebase = repository.rebase(branch, upstream, onto, Some(&mut opts))?;
+    let mut commits = vec![];
+    let mut head = onto
+      .or(upstream)
+      .map(|c| c.id())
+      .ok_or_else(|| anyhow::Error::msg("Either upstream or onto must be given"))?;
+
+    while let Some(op) = rebase.next() {
+      op?;
+
+      let index = rebase.inmemory_index()?;
+      if index.has_conflicts() {
+        let paths = index
+          .conflicts()?
+          .map(|c| {
+            c.map(|c| {
+              let entry = c.our.or(c.their).or(c.ancestor);
+              entry
+                .map(|e| String::from_utf8_lossy(&e.path).to_string())
+                .unwrap_or_default()
+            })
+          })
+          .collect::<Result<Vec<_>, _>>()?;
+
+        let idx = rebase.operation_current().unwrap_or_default();
+        let operation = rebase
+          .nth(idx)
+          .map(|op| RebaseOperation::new(idx, &op))
+          .ok_or_else(|| anyhow::Error::msg("No current rebase operation"))?;
+
+        rebase.abort()?;
+
+        return Ok(InMemoryRebaseResult {
+          commits: commits.into_iter().map(Oid).collect(),
+          head: None,
+          updated_ref: None,
+          conflict: Some(RebaseConflict { operation, paths }),
+        });
+      }
+
+      match rebase.commit(None, &committer, None) {
+        Ok(oid) => {
+          commits.push(oid);
+          head = oid;
+        }
+        Err(e) if e.code() == git2::ErrorCode::Applied => {}
+        Err(e) => return Err(e.into()),
+      }
+    }
+
+    rebase.finish(None)?;
+
+    let updated_ref = match branch_ref {
+      Some((name, orig_head)) => {
+        repository.reference_matching(
+          &name,
+          head,
+          true,
+          orig_head,
+          "rebase (in-memory): finished",
+        )?;
+
+        Some(name)
+      }
+      None => None,
+    };
+
+    Ok(InMemoryRebaseResult {
+      commits: commits.into_iter().map(Oid).collect(),
+      head: Some(Oid(head)),
+      updated_ref,
+      conflict: None,
+    })
+  }
+}
+
+#[napi]
+impl Task for RebaseInMemory {
+  type Output = InMemoryRebaseResult;
+  type JsValue = InMemoryRebaseResult;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.rebase()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
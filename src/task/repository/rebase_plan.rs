@@ -0,0 +1,89 @@
+use crate::rebase_plan::{self, RebasePlan, RebasePlanOptions};
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct CreateRebasePlan {
+  repository: Reference<Repository>,
+  upstream: String,
+  options: RebasePlanOptions,
+}
+
+impl CreateRebasePlan {
+  pub fn new(
+    repository: Reference<Repository>,
+    upstream: String,
+    options: RebasePlanOptions,
+  ) -> Self {
+    Self {
+      repository,
+      upstream,
+      options,
+    }
+  }
+}
+
+#[napi]
+impl Task for CreateRebasePlan {
+  type Output = RebasePlan;
+  type JsValue = RebasePlan;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    Ok(rebase_plan::create(
+      &repository,
+      &self.upstream,
+      &self.options,
+    )?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct RunRebasePlan {
+  repository: Reference<Repository>,
+  plan: Option<RebasePlan>,
+  abort: bool,
+}
+
+impl RunRebasePlan {
+  pub fn new(repository: Reference<Repository>, plan: RebasePlan, abort: bool) -> Self {
+    Self {
+      repository,
+      plan: Some(plan),
+      abort,
+    }
+  }
+
+  fn run(&mut self) -> anyhow::Result<RebasePlan> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut plan = self
+      .plan
+      .take()
+      .ok_or_else(|| anyhow::Error::msg("The rebase plan has already been run"))?;
+
+    if self.abort {
+      rebase_plan::abort(&repository, &plan)?;
+    } else {
+      rebase_plan::run(&repository, &mut plan)?;
+    }
+
+    Ok(plan)
+  }
+}
+
+#[napi]
+impl Task for RunRebasePlan {
+  type Output = RebasePlan;
+  type JsValue = RebasePlan;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.run()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
@@ -0,0 +1,65 @@
+use crate::cherrypick::CherrypickOptions;
+use crate::repository::Repository;
+use crate::sequencer::{self, Sequencer, SequencerAction};
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct StartSequence {
+  repository: Reference<Repository>,
+  range: Option<(SequencerAction, String)>,
+  options: CherrypickOptions,
+}
+
+impl StartSequence {
+  pub fn new(
+    repository: Reference<Repository>,
+    range: Option<(SequencerAction, String)>,
+    options: CherrypickOptions,
+  ) -> Self {
+    Self {
+      repository,
+      range,
+      options,
+    }
+  }
+
+  fn start(&self) -> anyhow::Result<()> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    match &self.range {
+      Some((action, range)) => {
+        sequencer::start(&repository, *action, range, &self.options)?;
+        if let Err(e) = sequencer::run(&repository, &self.options) {
+          // Do not leave a half-applied sequence behind if it cannot be started.
+          if sequencer::abort(&repository).is_err() {
+            repository.cleanup_state()?;
+          }
+
+          return Err(e);
+        }
+      }
+      None if !sequencer::is_in_progress(&repository) => {
+        anyhow::bail!("No sequence in progress");
+      }
+      None => {}
+    }
+
+    Ok(())
+  }
+}
+
+#[napi]
+impl Task for StartSequence {
+  type Output = ();
+  type JsValue = Sequencer;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.start()?)
+  }
+
+  fn resolve(&mut self, env: Env, _: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(Sequencer::new(
+      self.repository.clone(env)?,
+      std::mem::take(&mut self.options),
+    ))
+  }
+}
@@ -0,0 +1,106 @@
+use crate::commit::Signature;
+use crate::object::Oid;
+use crate::repository::Repository;
+use crate::stash::{StashApplyOpts, StashFlags};
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct SaveStash {
+  repository: Reference<Repository>,
+  stasher: Signature,
+  message: Option<String>,
+  flags: StashFlags,
+}
+
+impl SaveStash {
+  pub fn new(
+    repository: Reference<Repository>,
+    stasher: Signature,
+    message: Option<String>,
+    flags: StashFlags,
+  ) -> Self {
+    Self {
+      repository,
+      stasher,
+      message,
+      flags,
+    }
+  }
+
+  fn save(&self) -> anyhow::Result<Oid> {
+    let mut repository = futures::executor::block_on(self.repository.repository.lock());
+    let stasher = git2::Signature::try_from(self.stasher.clone())?;
+    let oid = repository.stash_save2(
+      &stasher,
+      self.message.as_deref(),
+      Some((&self.flags).into()),
+    )?;
+
+    Ok(Oid(oid))
+  }
+}
+
+#[napi]
+impl Task for SaveStash {
+  type Output = Oid;
+  type JsValue = Oid;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.save()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
+
+pub struct ApplyStash {
+  repository: Reference<Repository>,
+  index: usize,
+  options: StashApplyOpts,
+  pop: bool,
+}
+
+impl ApplyStash {
+  pub fn new(
+    repository: Reference<Repository>,
+    index: usize,
+    options: StashApplyOpts,
+    pop: bool,
+  ) -> Self {
+    Self {
+      repository,
+      index,
+      options,
+      pop,
+    }
+  }
+
+  fn apply(&self) -> anyhow::Result<()> {
+    let mut repository = futures::executor::block_on(self.repository.repository.lock());
+    let mut opts = git2::StashApplyOptions::new();
+    self.options.configure(&mut opts);
+
+    if self.pop {
+      repository.stash_pop(self.index, Some(&mut opts))?;
+    } else {
+      repository.stash_apply(self.index, Some(&mut opts))?;
+    }
+
+    Ok(())
+  }
+}
+
+#[napi]
+impl Task for ApplyStash {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    self.apply().map_err(|e| self.options.map_error(e))
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
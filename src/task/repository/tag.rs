@@ -0,0 +1,61 @@
+use crate::commit::Signature;
+use crate::object::Oid;
+use crate::repository::Repository;
+use napi::bindgen_prelude::Reference;
+use napi::{Env, Task};
+
+pub struct CreateTag {
+  repository: Reference<Repository>,
+  name: String,
+  target: Oid,
+  /// The tagger and the message of an annotated tag, none for a lightweight one.
+  annotation: Option<(Signature, String)>,
+  force: bool,
+}
+
+impl CreateTag {
+  pub fn new(
+    repository: Reference<Repository>,
+    name: String,
+    target: Oid,
+    annotation: Option<(Signature, String)>,
+    force: bool,
+  ) -> Self {
+    Self {
+      repository,
+      name,
+      target,
+      annotation,
+      force,
+    }
+  }
+
+  fn create(&self) -> anyhow::Result<Oid> {
+    let repository = futures::executor::block_on(self.repository.repository.lock());
+    let target = repository.find_object(self.target.0, None)?;
+
+    let oid = match &self.annotation {
+      Some((tagger, message)) => {
+        let tagger = git2::Signature::try_from(tagger.clone())?;
+        repository.tag(&self.name, &target, &tagger, message, self.force)?
+      }
+      None => repository.tag_lightweight(&self.name, &target, self.force)?,
+    };
+
+    Ok(Oid(oid))
+  }
+}
+
+#[napi]
+impl Task for CreateTag {
+  type Output = Oid;
+  type JsValue = Oid;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.create()?)
+  }
+
+  fn resolve(&mut self, _: Env, value: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(value)
+  }
+}
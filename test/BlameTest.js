@@ -0,0 +1,34 @@
+import { Signature } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class BlameTest extends GitTestCase {
+    async testBlame() {
+        const repo = this._repo;
+        const sig = await repo.signature();
+        const other = new Signature('other', 'other@example.com', sig.time());
+
+        const first = await this._commit('README.md', 'one\ntwo\n', 'first commit', sig);
+        const second = await this._commit('README.md', 'one\ntwo\nthree\n', 'second commit', other);
+
+        const hunks = await repo.blame('README.md');
+        __self.assertCount(2, hunks);
+        __self.assertEquals(first.toString(), hunks[0].finalCommitId.toString());
+        __self.assertEquals(1, hunks[0].finalStartLine);
+        __self.assertEquals(2, hunks[0].linesInHunk);
+        __self.assertEquals('test', hunks[0].finalSignature.name());
+        __self.assertEquals('README.md', hunks[0].origPath);
+        __self.assertEquals(second.toString(), hunks[1].finalCommitId.toString());
+        __self.assertEquals(3, hunks[1].finalStartLine);
+        __self.assertEquals(1, hunks[1].linesInHunk);
+        __self.assertEquals('other', hunks[1].finalSignature.name());
+
+        const range = await repo.blame('README.md', { minLine: 3, maxLine: 3 });
+        __self.assertCount(1, range);
+        __self.assertEquals(second.toString(), range[0].finalCommitId.toString());
+
+        const old = await repo.blame('README.md', { newestCommit: first });
+        __self.assertCount(1, old);
+        __self.assertEquals(2, old[0].linesInHunk);
+    }
+}
@@ -0,0 +1,107 @@
+import { BranchType } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class BranchTest extends GitTestCase {
+    async _names(branches) {
+        return (await Promise.all(branches.map((b) => b.name()))).sort();
+    }
+
+    async _branch(name) {
+        for (const branch of await this._repo.branches(BranchType.Local)) {
+            if (await branch.name() === name) {
+                return branch;
+            }
+        }
+
+        return null;
+    }
+
+    async testListBranches() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        __self.assertEquals([ 'feature', 'master' ], await this._names(await repo.branches(BranchType.Local)));
+        const feature = await this._branch('feature');
+        const master = await this._branch('master');
+        __self.assertFalse(await feature.isHead());
+        __self.assertTrue(await master.isHead());
+
+        await repo.checkout('refs/heads/feature');
+        __self.assertTrue(await feature.isHead());
+        __self.assertFalse(await master.isHead());
+
+        __self.assertCount(0, await repo.branches(BranchType.Remote));
+        __self.assertCount(2, await repo.branches());
+    }
+
+    async testUpstream() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        const feature = await this._branch('feature');
+        __self.assertNull(await feature.upstream());
+
+        await feature.setUpstream('master');
+        __self.assertEquals('master', await (await feature.upstream()).name());
+
+        await feature.setUpstream(null);
+        __self.assertNull(await feature.upstream());
+    }
+
+    async testRenameAndDelete() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        const feature = await this._branch('feature');
+        await feature.rename('renamed', false);
+        __self.assertEquals('renamed', await feature.name());
+        __self.assertEquals([ 'master', 'renamed' ], await this._names(await repo.branches(BranchType.Local)));
+
+        await feature.delete();
+        __self.assertEquals([ 'master' ], await this._names(await repo.branches(BranchType.Local)));
+
+        const [ master ] = await repo.branches(BranchType.Local);
+        let error = null;
+        try {
+            await master.delete();
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testGraphAheadBehind() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        const third = await this._commit('README.md', 'Example\nThird\n', 'third commit');
+
+        __self.assertEquals({ ahead: 2, behind: 0 }, { ...await repo.graphAheadBehind(third, base) });
+        __self.assertEquals({ ahead: 0, behind: 1 }, { ...await repo.graphAheadBehind(second, third) });
+    }
+
+    async testBranchTrackingSummary() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+        await repo.createBranch('old', base, false);
+        await repo.createBranch('orphan', base, false);
+        await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+
+        await (await this._branch('feature')).setUpstream('master');
+        await (await this._branch('orphan')).setUpstream('old');
+        await (await this._branch('old')).delete();
+
+        const summary = await repo.branchTrackingSummary();
+        __self.assertEquals([
+            { name: 'feature', upstream: 'refs/heads/master', gone: false, ahead: 0, behind: 1 },
+            { name: 'master', gone: false, ahead: 0, behind: 0 },
+            { name: 'orphan', upstream: 'refs/heads/old', gone: true, ahead: 0, behind: 0 },
+        ], summary.map((t) => ({ ...t })).sort((a, b) => a.name.localeCompare(b.name)));
+    }
+}
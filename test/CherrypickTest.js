@@ -0,0 +1,58 @@
+import { RepositoryState } from '../index';
+import { existsSync } from 'node:fs';
+import { sep } from 'node:path';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class CherrypickTest extends GitTestCase {
+    async testCherrypick() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        await repo.checkout('refs/heads/feature');
+        const feature = await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await repo.checkout('refs/heads/master');
+
+        await repo.cherrypick(await repo.findCommit(feature));
+        __self.assertEquals(RepositoryState.CherryPick, await repo.state());
+
+        const statuses = await repo.statuses();
+        __self.assertEquals([ 'FEATURE.md' ], statuses.map((s) => s.path));
+        __self.assertTrue(existsSync(this._tmpDirName + sep + 'FEATURE.md'));
+    }
+
+    async testRevert() {
+        const repo = this._repo;
+        await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('OTHER.md', 'Other\n', 'second commit');
+
+        await repo.revert(await repo.findCommit(second));
+        __self.assertEquals(RepositoryState.Revert, await repo.state());
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'OTHER.md'));
+    }
+
+    async testCherrypickAndRevertInMemory() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+        const master = await repo.findCommit(await this._commit('MASTER.md', 'Master\n', 'master commit'));
+
+        await repo.checkout('refs/heads/feature');
+        const feature = await repo.findCommit(await this._commit('FEATURE.md', 'Feature\n', 'feature commit'));
+
+        let index = await repo.cherrypickCommit(feature, master);
+        __self.assertFalse(await index.hasConflicts());
+
+        let tree = await repo.findTree(await index.writeTreeTo(repo));
+        __self.assertNotNull(tree.entryByPath('FEATURE.md'));
+        __self.assertNotNull(tree.entryByPath('MASTER.md'));
+
+        index = await repo.revertCommit(master, master);
+        tree = await repo.findTree(await index.writeTreeTo(repo));
+        const baseTree = (await repo.findCommit(base)).getTree();
+        __self.assertEquals(baseTree.oid().toString(), tree.oid().toString());
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+    }
+}
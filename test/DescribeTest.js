@@ -0,0 +1,53 @@
+import { DescribeStrategy } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class DescribeTest extends GitTestCase {
+    async testDescribeHead() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createTag('v1.0.0', first, await repo.signature(), 'Release 1.0.0', false);
+
+        __self.assertEquals('v1.0.0', await repo.describe());
+        __self.assertEquals('v1.0.0-0-g' + first.toString().substring(0, 7), await repo.describe({ alwaysUseLongFormat: true }));
+
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        __self.assertEquals('v1.0.0-1-g' + second.toString().substring(0, 10), await repo.describe({ abbreviatedSize: 10 }));
+
+        await this._writeFile('README.md', 'Dirty\n');
+
+        __self.assertEquals('v1.0.0-1-g' + second.toString().substring(0, 7) + '-dirty', await repo.describe({ dirtySuffix: '-dirty' }));
+    }
+
+    async testDescribeStrategies() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createLightweightTag('light', first, false);
+
+        let error = null;
+        try {
+            await repo.describe();
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+        __self.assertEquals('light', await repo.describe({ strategy: DescribeStrategy.Tags }));
+        __self.assertEquals('tags/light', await repo.describe({ strategy: DescribeStrategy.All }));
+        __self.assertEquals(first.toString().substring(0, 7), await repo.describe({ showCommitOidAsFallback: true }));
+    }
+
+    async testDescribeCommit() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const tagger = await repo.signature();
+        await repo.createTag('v1.0.0', first, tagger, 'Release 1.0.0', false);
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        await repo.createTag('v2.0.0', second, tagger, 'Release 2.0.0', false);
+        const third = await this._commit('README.md', 'Example\nThird\n', 'third commit');
+
+        __self.assertEquals('v1.0.0', await (await repo.findCommit(first)).describe(repo));
+        __self.assertEquals('v2.0.0-1-g' + third.toString().substring(0, 7), await (await repo.findCommit(third)).describe(repo));
+        __self.assertEquals('v1.0.0-2-g' + third.toString().substring(0, 7), await (await repo.findCommit(third)).describe(repo, { pattern: 'v1.*' }));
+    }
+}
@@ -0,0 +1,151 @@
+import { ApplyLocation, Delta, Diff, DiffFormat, FileMode } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class DiffTest extends GitTestCase {
+    async testDiffTreeToTree() {
+        const repo = this._repo;
+
+        await this._writeFile('README.md', 'one\ntwo\nthree\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        const oldTree = await repo.findTree(await index.writeTree());
+
+        await this._writeFile('README.md', 'one\n2\nthree\nfour\n');
+        await this._writeFile('EXAMPLE.md', 'Example\n');
+        await index.addPath('README.md');
+        await index.addPath('EXAMPLE.md');
+        const newTree = await repo.findTree(await index.writeTree());
+
+        const diff = await repo.diffTreeToTree(oldTree, newTree);
+        const deltas = await diff.deltas();
+        __self.assertCount(2, deltas);
+        __self.assertEquals('EXAMPLE.md', deltas[0].newFile.path);
+        __self.assertEquals(Delta.Added, deltas[0].status);
+        __self.assertEquals('README.md', deltas[1].oldFile.path);
+        __self.assertEquals(Delta.Modified, deltas[1].status);
+        __self.assertEquals(FileMode.Blob, deltas[1].newFile.mode);
+        __self.assertEquals(newTree.entryByPath('README.md').oid().toString(), deltas[1].newFile.oid.toString());
+
+        const hunks = await diff.hunks(1);
+        __self.assertCount(1, hunks);
+        __self.assertEquals(1, hunks[0].oldStart);
+        __self.assertEquals(3, hunks[0].oldLines);
+        __self.assertEquals(4, hunks[0].newLines);
+        __self.assertEquals([ ' ', '-', '+', ' ', '+' ], hunks[0].lines.map((l) => l.origin));
+        __self.assertEquals('2\n', hunks[0].lines[2].content);
+
+        const stats = await diff.stats();
+        __self.assertEquals(2, stats.filesChanged);
+        __self.assertEquals(3, stats.insertions);
+        __self.assertEquals(1, stats.deletions);
+    }
+
+    async testDiffIndexAndWorkdir() {
+        const repo = this._repo;
+
+        await this._writeFile('README.md', 'Example\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        const tree = await repo.findTree(await index.writeTree());
+
+        await this._writeFile('EXAMPLE.md', 'Example\n');
+        await index.addPath('EXAMPLE.md');
+        await this._writeFile('README.md', 'Example\nChanged\n');
+
+        const staged = await repo.diffTreeToIndex(tree, index);
+        __self.assertEquals([ 'EXAMPLE.md' ], (await staged.deltas()).map((d) => d.newFile.path));
+
+        const unstaged = await repo.diffIndexToWorkdir(index);
+        __self.assertEquals([ 'README.md' ], (await unstaged.deltas()).map((d) => d.newFile.path));
+
+        const all = await repo.diffTreeToWorkdirWithIndex(tree);
+        __self.assertEquals(2, (await all.stats()).filesChanged);
+    }
+
+    async testDiffToPatchRoundTrip() {
+        const repo = this._repo;
+
+        await this._writeFile('README.md', 'one\ntwo\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        const oldTree = await repo.findTree(await index.writeTree());
+
+        await this._writeFile('README.md', 'one\n2\n');
+        await index.addPath('README.md');
+        const newTree = await repo.findTree(await index.writeTree());
+
+        const diff = await repo.diffTreeToTree(oldTree, newTree);
+        const patch = await diff.toPatch();
+        __self.assertEquals(
+            'diff --git a/README.md b/README.md\n' +
+            'index 814f4a4..99b356d 100644\n' +
+            '--- a/README.md\n' +
+            '+++ b/README.md\n' +
+            '@@ -1,2 +1,2 @@\n' +
+            ' one\n' +
+            '-two\n' +
+            '+2\n',
+            patch,
+        );
+        __self.assertEquals('README.md\n', await diff.toPatch(DiffFormat.NameOnly));
+        __self.assertEquals('M\tREADME.md\n', await diff.toPatch(DiffFormat.NameStatus));
+
+        const parsed = Diff.fromBuffer(patch);
+        __self.assertEquals(patch, await parsed.toPatch());
+        __self.assertEquals('README.md', (await parsed.deltas())[0].newFile.path);
+        __self.assertEquals(1, (await parsed.stats()).insertions);
+    }
+
+    async testApply() {
+        const repo = this._repo;
+
+        await this._writeFile('README.md', 'one\ntwo\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        const tree = await repo.findTree(await index.writeTree());
+
+        const diff = Diff.fromBuffer(
+            'diff --git a/README.md b/README.md\n' +
+            '--- a/README.md\n' +
+            '+++ b/README.md\n' +
+            '@@ -1,2 +1,2 @@\n' +
+            ' one\n' +
+            '-two\n' +
+            '+2\n',
+        );
+
+        __self.assertEquals([], await repo.apply(diff, ApplyLocation.Workdir, { check: true }));
+        __self.assertCount(0, await (await repo.diffIndexToWorkdir()).deltas());
+
+        __self.assertEquals([], await repo.apply(diff, ApplyLocation.Both));
+        __self.assertCount(0, await (await repo.diffIndexToWorkdir()).deltas());
+        __self.assertCount(1, await (await repo.diffTreeToIndex(tree)).deltas());
+
+        __self.assertEquals([ 'README.md' ], await repo.apply(diff, ApplyLocation.Workdir, { check: true }));
+    }
+
+    async testApplyToTree() {
+        const repo = this._repo;
+
+        await this._writeFile('README.md', 'one\ntwo\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        const tree = await repo.findTree(await index.writeTree());
+
+        await this._writeFile('README.md', 'one\n2\n');
+        await index.addPath('README.md');
+        const newTree = await repo.findTree(await index.writeTree());
+        const diff = await repo.diffTreeToTree(tree, newTree);
+
+        const result = await repo.applyToTree(tree, diff);
+        __self.assertEquals(newTree.oid().toString(), (await result.writeTreeTo(repo)).toString());
+
+        try {
+            await repo.applyToTree(newTree, diff);
+            __self.fail('Apply should have failed');
+        } catch (e) {
+            __self.assertEquals('patch does not apply to README.md', e.message);
+        }
+    }
+}
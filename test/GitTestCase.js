@@ -0,0 +1,78 @@
+import { InitOptions, Repository } from '../index';
+import { randomBytes } from 'node:crypto';
+import { sep } from 'node:path';
+import { tmpdir } from 'node:os';
+
+const Filesystem = Jymfony.Component.Filesystem.Filesystem;
+const File = Jymfony.Component.Filesystem.File;
+const TestCase = Jymfony.Component.Testing.Framework.TestCase;
+
+const fs = new Filesystem();
+
+/**
+ * Base test case initializing an empty repository (with "master" as initial head)
+ * in a temporary directory before each test.
+ * Test cases needing an empty directory can override `_createRepository` to return null.
+ */
+export default class GitTestCase extends TestCase {
+    _tmpDirName;
+    _repo;
+
+    async beforeEach() {
+        this._tmpDirName = tmpdir() + sep + randomBytes(5).toString('base64').replace(/[^0-9a-z]/i, '-');
+        await fs.mkdir(this._tmpDirName);
+
+        this._repo = await this._createRepository();
+    }
+
+    async afterEach() {
+        await fs.remove(this._tmpDirName);
+    }
+
+    async _createRepository() {
+        const opts = new InitOptions();
+        opts.setInitialHead('master');
+
+        const repo = await Repository.init(this._tmpDirName, opts);
+        const config = await repo.config();
+        config.setStr('user.name', 'test');
+        config.setStr('user.email', 'test@example.com');
+
+        return repo;
+    }
+
+    async _writeFile(fileName, content) {
+        const file = new File(this._tmpDirName + sep + fileName);
+        const openFile = await file.openFile('w+');
+        await openFile.fwrite(Buffer.from(content));
+        await openFile.close();
+    }
+
+    /**
+     * Writes the given file, stages it and commits on top of HEAD.
+     */
+    async _commit(fileName, content, message, signature = null) {
+        await this._writeFile(fileName, content);
+
+        const index = await this._repo.index();
+        await index.addPath(fileName);
+        await index.write();
+        const tree = await this._repo.findTree(await index.writeTree());
+
+        const head = await this._repo.head().catch(() => null);
+        const parents = head ? [ await this._repo.findCommit(head.target()) ] : [];
+        const sig = signature || await this._repo.signature();
+
+        return this._repo.createCommit('HEAD', sig, sig, message, tree, parents);
+    }
+
+    /**
+     * Creates a dangling commit with the given parents, reusing the tree of the first one.
+     */
+    async _commitOn(parents, message, signature = null) {
+        const commits = await Promise.all(parents.map((oid) => this._repo.findCommit(oid)));
+        const sig = signature || await this._repo.signature();
+
+        return this._repo.createCommit(null, sig, sig, message, commits[0].getTree(), commits);
+    }
+}
@@ -0,0 +1,75 @@
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class GraphTest extends GitTestCase {
+    async testMergeBase() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        const left = await this._commitOn([ base ], 'left');
+        const right = await this._commitOn([ base ], 'right');
+
+        __self.assertEquals(base.toString(), (await repo.mergeBase(left, right)).toString());
+        __self.assertEquals([ base.toString() ], (await repo.mergeBases(left, right)).map(String));
+
+        const orphan = await repo.createCommit(null, await repo.signature(), await repo.signature(), 'orphan', (await repo.findCommit(base)).getTree(), []);
+        let error = null;
+        try {
+            await repo.mergeBase(left, orphan);
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testMergeBasesCrissCross() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        const left = await this._commitOn([ base ], 'left');
+        const right = await this._commitOn([ base ], 'right');
+        const leftMerge = await this._commitOn([ left, right ], 'merge right into left');
+        const rightMerge = await this._commitOn([ right, left ], 'merge left into right');
+
+        const bases = (await repo.mergeBases(leftMerge, rightMerge)).map(String).sort();
+        __self.assertEquals([ left.toString(), right.toString() ].sort(), bases);
+    }
+
+    async testMergeBaseManyAndOctopus() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        const one = await this._commitOn([ base ], 'one');
+        const two = await this._commitOn([ one ], 'two');
+        const three = await this._commitOn([ one ], 'three');
+        const four = await this._commitOn([ base ], 'four');
+
+        __self.assertEquals(one.toString(), (await repo.mergeBaseMany([ two, three, four ])).toString());
+        __self.assertEquals(base.toString(), (await repo.mergeBaseOctopus([ two, three, four ])).toString());
+        __self.assertEquals(one.toString(), (await repo.mergeBaseOctopus([ two, three ])).toString());
+
+        let error = null;
+        try {
+            await repo.mergeBaseOctopus([ two ]);
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testGraphQueries() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        const left = await this._commitOn([ base ], 'left');
+        const right = await this._commitOn([ base ], 'right');
+
+        __self.assertTrue(await repo.graphDescendantOf(left, base));
+        __self.assertFalse(await repo.graphDescendantOf(base, left));
+        __self.assertFalse(await repo.graphDescendantOf(left, left));
+        __self.assertFalse(await repo.graphDescendantOf(left, right));
+
+        __self.assertTrue(await repo.graphReachableFromAny(base, [ left, right ]));
+        __self.assertTrue(await repo.graphReachableFromAny(left, [ right, left ]));
+        __self.assertFalse(await repo.graphReachableFromAny(left, [ right ]));
+        __self.assertFalse(await repo.graphReachableFromAny(left, []));
+    }
+}
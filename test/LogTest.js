@@ -0,0 +1,107 @@
+import { Signature } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class LogTest extends GitTestCase {
+    async _commitAs(name, time, message, fileName = 'README.md') {
+        const when = (await this._repo.signature()).time();
+        when.time = time;
+        when.offset = 0;
+
+        return this._commit(fileName, message + '\n', message, new Signature(name, name.toLowerCase() + '@example.com', when));
+    }
+
+    async _merge(parents, time, message) {
+        const when = (await this._repo.signature()).time();
+        when.time = time;
+
+        return this._commitOn(parents, message, new Signature('Merger', 'merger@example.com', when));
+    }
+
+    async testLogDefaults() {
+        const repo = this._repo;
+        await this._commitAs('Alice', 1000, 'first commit');
+        await this._commitAs('Bob', 2000, 'second commit');
+        await this._commitAs('Alice', 3000, 'third commit');
+
+        const log = await repo.log();
+        __self.assertEquals([ 'third commit', 'second commit', 'first commit' ], log.map((c) => c.summary));
+        __self.assertEquals('Alice', log[0].author.name());
+        __self.assertEquals(3000, log[0].time.time);
+    }
+
+    async testLogFilters() {
+        const repo = this._repo;
+        await this._commitAs('Alice', 1000, 'feat: first');
+        await this._commitAs('Bob', 2000, 'fix: second');
+        await this._commitAs('Alice', 3000, 'feat: third');
+        await this._commitAs('Carol', 4000, 'chore: fourth');
+
+        const summaries = async (options) => (await repo.log(options)).map((c) => c.summary);
+
+        __self.assertEquals([ 'feat: third', 'feat: first' ], await summaries({ author: '^Alice' }));
+        __self.assertEquals([ 'fix: second' ], await summaries({ committer: 'bob@example\\.com' }));
+        __self.assertEquals([], await summaries({ author: 'ALICE' }));
+        __self.assertEquals([ 'feat: third', 'feat: first' ], await summaries({ author: 'ALICE', ignoreCase: true }));
+        __self.assertEquals([ 'feat: third', 'feat: first' ], await summaries({ grep: '^feat:' }));
+        __self.assertEquals([ 'feat: third', 'fix: second' ], await summaries({ since: 2000, until: 3000 }));
+        __self.assertEquals([ 'feat: third', 'fix: second' ], await summaries({ skip: 1, maxCount: 2 }));
+        __self.assertEquals([ 'feat: first' ], await summaries({ grep: 'feat', skip: 1 }));
+
+        let error = null;
+        try {
+            await repo.log({ grep: '(' });
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testLogRevisionsAndPaths() {
+        const repo = this._repo;
+        const first = await this._commitAs('Alice', 1000, 'first', 'README.md');
+        await this._commitAs('Alice', 2000, 'second', 'lib.rs');
+        const third = await this._commitAs('Alice', 3000, 'third', 'README.md');
+        await repo.createLightweightTag('v1', first, false);
+
+        const summaries = async (options) => (await repo.log(options)).map((c) => c.summary);
+
+        __self.assertEquals([ 'third', 'second' ], await summaries({ revisions: [ 'v1..HEAD' ] }));
+        __self.assertEquals([ 'third', 'second' ], await summaries({ revisions: [ 'HEAD', '^v1' ] }));
+        __self.assertEquals([ 'second', 'first' ], await summaries({ revisions: [ third.toString() + '~1' ] }));
+        __self.assertEquals([ 'third', 'first' ], await summaries({ paths: [ 'README.md' ] }));
+    }
+
+    async testLogMerges() {
+        const repo = this._repo;
+        const first = await this._commitAs('Alice', 1000, 'first');
+        const second = await this._commitAs('Alice', 2000, 'second');
+        const side = await repo.createCommit(null, await repo.signature(), await repo.signature(), 'side', (await repo.findCommit(first)).getTree(), [ await repo.findCommit(first) ]);
+        const merge = await this._merge([ second, side ], 4000, 'merge');
+        await repo.createBranch('merged', merge, false);
+
+        const summaries = async (options) => (await repo.log({ revisions: [ 'merged' ], ...options })).map((c) => c.summary);
+
+        __self.assertEquals([ 'merge' ], await summaries({ merges: true }));
+        __self.assertCount(3, await summaries({ noMerges: true }));
+        __self.assertEquals([ 'merge', 'second', 'first' ], await summaries({ firstParent: true }));
+    }
+
+    async testLogFirstParentPaths() {
+        const repo = this._repo;
+        const first = await this._commitAs('Alice', 1000, 'first');
+        const side = await this._commitAs('Bob', 2000, 'side', 'lib.rs');
+        const main = await this._commitOn([ first ], 'main');
+
+        const sig = await repo.signature();
+        const parents = [ await repo.findCommit(main), await repo.findCommit(side) ];
+        const merge = await repo.createCommit(null, sig, sig, 'merge', parents[1].getTree(), parents);
+        await repo.createBranch('merged', merge, false);
+
+        const summaries = async (options) => (await repo.log({ revisions: [ 'merged' ], paths: [ 'lib.rs' ], ...options })).map((c) => c.summary);
+
+        __self.assertEquals([ 'side' ], await summaries({}));
+        __self.assertEquals([ 'merge' ], await summaries({ firstParent: true }));
+    }
+}
@@ -0,0 +1,88 @@
+import { RepositoryState } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class MergeTest extends GitTestCase {
+    async testMergeAnalysisAndMerge() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        await repo.checkout('refs/heads/feature');
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await repo.checkout('refs/heads/master');
+
+        const feature = await repo.referenceToAnnotatedCommit(await repo.getReference('refs/heads/feature'));
+        __self.assertEquals('refs/heads/feature', feature.refname());
+
+        let analysis = await repo.mergeAnalysis([ feature ]);
+        __self.assertTrue(analysis.fastForward);
+        __self.assertFalse(analysis.upToDate);
+
+        await this._commit('README.md', 'Example\nChanged\n', 'master commit');
+        analysis = await repo.mergeAnalysis([ feature ]);
+        __self.assertFalse(analysis.fastForward);
+        __self.assertTrue(analysis.normal);
+
+        await repo.merge([ feature ]);
+        __self.assertEquals(RepositoryState.Merge, await repo.state());
+
+        const index = await repo.index();
+        __self.assertFalse(await index.hasConflicts());
+
+        const statuses = await repo.statuses();
+        __self.assertEquals([ 'FEATURE.md' ], statuses.map((s) => s.path));
+
+        const upToDate = await repo.findAnnotatedCommit(base);
+        __self.assertTrue((await repo.mergeAnalysis([ upToDate ])).upToDate);
+    }
+
+    async testMergeCommitsInMemory() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        const ours = await this._commit('README.md', 'Ours\n', 'master commit');
+        await repo.checkout('refs/heads/feature');
+        const theirs = await this._commit('README.md', 'Theirs\n', 'feature commit');
+
+        const index = await repo.mergeCommits(await repo.findCommit(ours), await repo.findCommit(theirs));
+        __self.assertTrue(await index.hasConflicts());
+
+        const conflicts = await index.conflicts();
+        __self.assertCount(1, conflicts);
+        __self.assertEquals('README.md', conflicts[0].ancestor.path);
+        __self.assertEquals('README.md', conflicts[0].our.path);
+        __self.assertEquals('README.md', conflicts[0].their.path);
+
+        await index.addEntry('README.md', conflicts[0].their.oid, conflicts[0].their.mode);
+        __self.assertFalse(await index.hasConflicts());
+
+        const tree = await repo.findTree(await index.writeTreeTo(repo));
+        const theirTree = (await repo.findCommit(theirs)).getTree();
+        __self.assertEquals(theirTree.oid().toString(), tree.oid().toString());
+    }
+
+    async testMergeTreesInMemory() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        const ours = await this._commit('OURS.md', 'Ours\n', 'master commit');
+        await repo.checkout('refs/heads/feature');
+        const theirs = await this._commit('THEIRS.md', 'Theirs\n', 'feature commit');
+
+        const index = await repo.mergeTrees(
+            (await repo.findCommit(base)).getTree(),
+            (await repo.findCommit(ours)).getTree(),
+            (await repo.findCommit(theirs)).getTree(),
+        );
+
+        __self.assertFalse(await index.hasConflicts());
+
+        const tree = await repo.findTree(await index.writeTreeTo(repo));
+        __self.assertNotNull(tree.entryByPath('OURS.md'));
+        __self.assertNotNull(tree.entryByPath('THEIRS.md'));
+        __self.assertNotNull(tree.entryByPath('README.md'));
+    }
+}
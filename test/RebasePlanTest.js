@@ -0,0 +1,187 @@
+import { RebasePlanAction, RebasePlanStatus, RepositoryState } from '../index';
+import { existsSync, readFileSync } from 'node:fs';
+import { sep } from 'node:path';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class RebasePlanTest extends GitTestCase {
+    async _messages(count) {
+        const messages = [];
+        let commit = await this._repo.findCommit((await this._repo.head()).target());
+        while (commit && messages.length < count) {
+            messages.push(commit.messageRaw().trim());
+            [ commit ] = commit.getParents();
+        }
+
+        return messages;
+    }
+
+    async _rejection(promise) {
+        try {
+            await promise;
+        } catch (e) {
+            return e;
+        }
+
+        return null;
+    }
+
+    async _prepareFeature() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+        await this._commit('README.md', 'Ours\n', 'master commit');
+        await repo.checkout('refs/heads/feature');
+    }
+
+    async testAutosquashPlan() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await this._commit('OTHER.md', 'Other\n', 'other commit');
+        await this._commit('FEATURE.md', 'Feature\nFixed\n', 'fixup! feature commit');
+        await this._commit('FEATURE.md', 'Feature\nFixed\nSquashed\n', 'squash! feature commit');
+
+        let plan = await repo.rebasePlan('master', { autosquash: true });
+        __self.assertEquals('refs/heads/feature', plan.branch);
+        __self.assertEquals(RebasePlanStatus.Pending, plan.status);
+        __self.assertEquals(
+            [ 'feature commit', 'fixup! feature commit', 'squash! feature commit', 'other commit' ],
+            plan.todo.map((s) => s.summary),
+        );
+        __self.assertEquals(
+            [ RebasePlanAction.Pick, RebasePlanAction.Fixup, RebasePlanAction.Squash, RebasePlanAction.Pick ],
+            plan.todo.map((s) => s.action),
+        );
+
+        plan = await repo.runRebasePlan(plan);
+        __self.assertEquals(RebasePlanStatus.Done, plan.status);
+        __self.assertEquals('refs/heads/feature', (await repo.head()).name());
+        __self.assertEquals(
+            [ 'other commit', 'feature commit\n\nsquash! feature commit', 'master commit' ],
+            await this._messages(3),
+        );
+    }
+
+    async testAutosquashMatchesUniqueIdPrefixes() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        const feature = await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await this._commit('OTHER.md', 'Other\n', 'other commit');
+        await this._commit('FEATURE.md', 'Feature\nFixed\n', 'fixup! ' + feature.toString().substring(0, 7));
+        await this._commit('SHORT.md', 'Short\n', 'fixup! ' + feature.toString().substring(0, 3));
+
+        const plan = await repo.rebasePlan('master', { autosquash: true });
+        __self.assertEquals(
+            [ RebasePlanAction.Pick, RebasePlanAction.Fixup, RebasePlanAction.Pick, RebasePlanAction.Pick ],
+            plan.todo.map((s) => s.action),
+        );
+        __self.assertEquals('feature commit', plan.todo[0].summary);
+        __self.assertEquals('other commit', plan.todo[2].summary);
+    }
+
+    async testSquashWithoutPreviousCommitIsRejected() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        const orig = await this._commit('OTHER.md', 'Other\n', 'other commit');
+
+        let plan = await repo.rebasePlan('master');
+        plan.todo[0].action = RebasePlanAction.Squash;
+
+        let error = await this._rejection(repo.runRebasePlan(plan));
+        __self.assertNotNull(error);
+        __self.assertStringContainsString('cannot \'squash\' without a previous commit', error.message);
+
+        plan = await repo.rebasePlan('master');
+        plan.todo[0].action = RebasePlanAction.Drop;
+        plan.todo[1].action = RebasePlanAction.Fixup;
+
+        error = await this._rejection(repo.runRebasePlan(plan));
+        __self.assertNotNull(error);
+        __self.assertStringContainsString('cannot \'fixup\' without a previous commit', error.message);
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals(orig.toString(), (await repo.head()).target().toString());
+    }
+
+    async testRewordDropAndEdit() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await this._commit('DROPPED.md', 'Dropped\n', 'dropped commit');
+        await this._commit('EDITED.md', 'Edited\n', 'edited commit');
+
+        let plan = await repo.rebasePlan('master');
+        plan.todo[0].action = RebasePlanAction.Reword;
+        plan.todo[0].message = 'reworded commit\n';
+        plan.todo[1].action = RebasePlanAction.Drop;
+        plan.todo[2].action = RebasePlanAction.Edit;
+
+        plan = await repo.runRebasePlan(plan);
+        __self.assertEquals(RebasePlanStatus.Edit, plan.status);
+        __self.assertEquals('edited commit', plan.current.summary);
+        __self.assertCount(0, plan.todo);
+
+        plan = await repo.runRebasePlan(plan);
+        __self.assertEquals(RebasePlanStatus.Done, plan.status);
+        __self.assertNull(plan.current);
+        __self.assertEquals([ 'edited commit', 'reworded commit', 'master commit' ], await this._messages(3));
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'DROPPED.md'));
+    }
+
+    async testConflictResumeFromSerializedPlan() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        await this._commit('README.md', 'Theirs\n', 'conflicting commit');
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+
+        let plan = await repo.runRebasePlan(await repo.rebasePlan('master'));
+        __self.assertEquals(RebasePlanStatus.Conflict, plan.status);
+        __self.assertEquals('conflicting commit', plan.current.summary);
+        __self.assertEquals(RepositoryState.CherryPick, await repo.state());
+
+        plan = JSON.parse(JSON.stringify(plan));
+
+        await this._writeFile('README.md', 'Resolved\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        await index.write();
+
+        plan = await repo.runRebasePlan(plan);
+        __self.assertEquals(RebasePlanStatus.Done, plan.status);
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals([ 'feature commit', 'conflicting commit', 'master commit' ], await this._messages(3));
+    }
+
+    async testAbortPlan() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        const orig = await this._commit('README.md', 'Theirs\n', 'conflicting commit');
+
+        const plan = await repo.runRebasePlan(await repo.rebasePlan('master'));
+        __self.assertEquals(RebasePlanStatus.Conflict, plan.status);
+
+        await repo.abortRebasePlan(plan);
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+
+        const head = await repo.head();
+        __self.assertEquals('refs/heads/feature', head.name());
+        __self.assertEquals(orig.toString(), head.target().toString());
+    }
+
+    async testPendingPlanKeepsWorkingDirectory() {
+        const repo = this._repo;
+        await this._prepareFeature();
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await this._writeFile('README.md', 'Dirty\n');
+
+        const plan = await repo.rebasePlan('master');
+        __self.assertNotNull(await this._rejection(repo.abortRebasePlan(plan)));
+        __self.assertNotNull(await this._rejection(repo.runRebasePlan(plan)));
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals('refs/heads/feature', (await repo.head()).name());
+        __self.assertEquals('Dirty\n', readFileSync(this._tmpDirName + sep + 'README.md', 'utf-8'));
+    }
+}
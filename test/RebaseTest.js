@@ -0,0 +1,152 @@
+import { RebaseOperationType, Repository, RepositoryState } from '../index';
+import { sep } from 'node:path';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class RebaseTest extends GitTestCase {
+    async _prepareConflictingBranches() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+
+        await this._commit('README.md', 'Ours\n', 'master commit');
+        await repo.checkout('refs/heads/feature');
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        await this._commit('README.md', 'Theirs\n', 'conflicting commit');
+
+        return {
+            branch: await repo.referenceToAnnotatedCommit(await repo.getReference('refs/heads/feature')),
+            upstream: await repo.referenceToAnnotatedCommit(await repo.getReference('refs/heads/master')),
+        };
+    }
+
+    async testRebaseStopsOnConflictAndAborts() {
+        const repo = this._repo;
+        const { branch, upstream } = await this._prepareConflictingBranches();
+        const featureHead = branch.id().toString();
+
+        const rebase = await repo.rebase({ branch, upstream });
+        const operations = await rebase.operations();
+        __self.assertCount(2, operations);
+        __self.assertEquals(RebaseOperationType.Pick, operations[0].kind);
+        __self.assertNull(await rebase.currentOperation());
+
+        const sig = await repo.signature();
+        const first = await rebase.next();
+        __self.assertEquals(0, first.index);
+        __self.assertNotNull(await rebase.commit(sig));
+
+        const second = await rebase.next();
+        __self.assertEquals(1, (await rebase.currentOperation()).index);
+        __self.assertEquals(operations[1].id.toString(), second.id.toString());
+        __self.assertEquals(RepositoryState.RebaseMerge, await repo.state());
+
+        const index = await rebase.index();
+        __self.assertTrue(await index.hasConflicts());
+
+        const reopened = await repo.openRebase();
+        __self.assertEquals(1, (await reopened.currentOperation()).index);
+        await reopened.abort();
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals(featureHead, (await repo.head()).target().toString());
+    }
+
+    async testRebaseResolveConflictAndFinish() {
+        const repo = this._repo;
+        const { branch, upstream } = await this._prepareConflictingBranches();
+        const sig = await repo.signature();
+
+        const rebase = await repo.rebase({ branch, upstream });
+        await rebase.next();
+        await rebase.commit(sig);
+        await rebase.next();
+
+        await this._writeFile('README.md', 'Resolved\n');
+        const index = await rebase.index();
+        await index.addPath('README.md');
+        __self.assertFalse(await index.hasConflicts());
+        await index.write();
+
+        const oid = await rebase.commit(sig, null, 'resolved commit');
+        __self.assertNull(await rebase.next());
+        await rebase.finish();
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+
+        const head = await repo.head();
+        __self.assertEquals('refs/heads/feature', head.name());
+        __self.assertEquals(oid.toString(), head.target().toString());
+
+        const commit = await repo.findCommit(oid);
+        __self.assertEquals('resolved commit', commit.messageRaw());
+
+        const [ parent ] = commit.getParents();
+        __self.assertEquals(upstream.id().toString(), parent.getParents()[0].oid().toString());
+    }
+
+    async testRebaseInMemoryReportsConflicts() {
+        const repo = this._repo;
+        const { branch, upstream } = await this._prepareConflictingBranches();
+        const featureHead = branch.id().toString();
+
+        const result = await repo.rebaseInMemory({ branch, upstream });
+        __self.assertNull(result.head);
+        __self.assertNull(result.updatedRef);
+        __self.assertCount(1, result.commits);
+        __self.assertEquals(1, result.conflict.operation.index);
+        __self.assertEquals([ 'README.md' ], result.conflict.paths);
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals(featureHead, (await repo.getReference('refs/heads/feature')).target().toString());
+    }
+
+    async testRebaseInMemoryInBareRepository() {
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await this._repo.createBranch('feature', base, false);
+        const master = await this._commit('MASTER.md', 'Master\n', 'master commit');
+        await this._repo.checkout('refs/heads/feature');
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+
+        (await this._repo.config()).setStr('core.bare', 'true');
+        const repo = await Repository.open(this._tmpDirName + sep + '.git');
+        __self.assertTrue(repo.isBare());
+
+        const result = await repo.rebaseInMemory({
+            branch: await repo.referenceToAnnotatedCommit(await repo.getReference('refs/heads/feature')),
+            upstream: await repo.findAnnotatedCommit(master),
+        });
+
+        __self.assertNull(result.conflict);
+        __self.assertCount(1, result.commits);
+        __self.assertEquals(result.commits[0].toString(), result.head.toString());
+        __self.assertEquals('refs/heads/feature', result.updatedRef);
+        __self.assertEquals(result.head.toString(), (await repo.getReference('refs/heads/feature')).target().toString());
+
+        const commit = await repo.findCommit(result.head);
+        __self.assertEquals('feature commit', commit.messageRaw());
+        __self.assertEquals(master.toString(), commit.getParents()[0].oid().toString());
+    }
+
+    async testRebaseInMemoryByIdDoesNotUpdateReferences() {
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await this._repo.createBranch('feature', base, false);
+        const master = await this._commit('MASTER.md', 'Master\n', 'master commit');
+        await this._repo.checkout('refs/heads/feature');
+        const feature = await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+
+        const result = await this._repo.rebaseInMemory({
+            branch: await this._repo.findAnnotatedCommit(feature),
+            upstream: await this._repo.findAnnotatedCommit(master),
+        });
+
+        __self.assertNull(result.conflict);
+        __self.assertNull(result.updatedRef);
+        __self.assertCount(1, result.commits);
+        __self.assertEquals(feature.toString(), (await this._repo.getReference('refs/heads/feature')).target().toString());
+
+        const commit = await this._repo.findCommit(result.head);
+        __self.assertEquals('feature commit', commit.messageRaw());
+        __self.assertEquals(master.toString(), commit.getParents()[0].oid().toString());
+    }
+}
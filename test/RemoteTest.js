@@ -0,0 +1,150 @@
+import { Credentials, InitOptions, Repository } from '../index';
+import { createServer } from 'node:http';
+import { getEventListeners } from 'node:events';
+import { randomBytes } from 'node:crypto';
+import { sep } from 'node:path';
+import { tmpdir } from 'node:os';
+
+const Filesystem = Jymfony.Component.Filesystem.Filesystem;
+const GitTestCase = Tests.GitTestCase;
+
+const fs = new Filesystem();
+
+export default class RemoteTest extends GitTestCase {
+    _remoteDirName;
+
+    async beforeEach() {
+        await super.beforeEach();
+
+        this._remoteDirName = tmpdir() + sep + randomBytes(5).toString('base64').replace(/[^0-9a-z]/i, '-');
+        await fs.mkdir(this._remoteDirName);
+    }
+
+    async afterEach() {
+        await super.afterEach();
+        await fs.remove(this._remoteDirName);
+    }
+
+    async _createRepository() {
+        // Some tests clone into the temporary directory, which must be empty.
+        return null;
+    }
+
+    async testPushReportsReferenceStatus() {
+        const bareOpts = new InitOptions();
+        bareOpts.setBare(true);
+        await Repository.init(this._remoteDirName, bareOpts);
+
+        const repo = this._repo = await super._createRepository();
+        await this._commit('README.md', 'Example\n', 'first commit');
+
+        const remote = await repo.createRemote('origin', 'file://' + this._remoteDirName);
+        const results = await remote.push([ 'refs/heads/master:refs/heads/master' ]);
+
+        __self.assertCount(1, results);
+        __self.assertEquals('refs/heads/master', results[0].refName);
+        __self.assertTrue(results[0].accepted);
+
+        const pushed = await Repository.open(this._remoteDirName);
+        const commit = await pushed.getBranchCommit('refs/heads/master');
+        __self.assertEquals('first commit', commit.messageRaw());
+    }
+
+    async testPushReportsTransferAndPackProgress() {
+        const bareOpts = new InitOptions();
+        bareOpts.setBare(true);
+        await Repository.init(this._remoteDirName, bareOpts);
+
+        const repo = this._repo = await super._createRepository();
+        await this._commit('README.md', 'Example\n', 'first commit');
+
+        const transferProgress = [];
+        const packProgress = [];
+        const remote = await repo.createRemote('origin', 'file://' + this._remoteDirName);
+        await remote.push([ 'refs/heads/master:refs/heads/master' ], {
+            pushTransferProgress: (p) => transferProgress.push(p),
+            packProgress: (p) => packProgress.push(p),
+        });
+
+        __self.assertGreaterThan(0, transferProgress.length);
+        const lastTransfer = transferProgress[transferProgress.length - 1];
+        __self.assertGreaterThan(0, lastTransfer.total);
+        __self.assertEquals(lastTransfer.total, lastTransfer.current);
+
+        __self.assertGreaterThan(0, packProgress.length);
+        const lastPack = packProgress[packProgress.length - 1];
+        __self.assertGreaterThan(0, lastPack.total);
+        __self.assertEquals(lastPack.total, lastPack.current);
+    }
+
+    async testDownloadReportsTransferProgress() {
+        this._repo = await super._createRepository();
+        const oid = await this._commit('README.md', 'Example\n', 'first commit');
+
+        const bareOpts = new InitOptions();
+        bareOpts.setBare(true);
+        const repo = await Repository.init(this._remoteDirName, bareOpts);
+
+        const progress = [];
+        const messages = [];
+        const remote = await repo.createRemote('origin', 'file://' + this._tmpDirName);
+        await remote.download([ 'refs/heads/master:refs/heads/master' ], {
+            transferProgress: (stats) => progress.push(stats),
+            sidebandProgress: (message) => messages.push(message),
+        });
+
+        __self.assertGreaterThan(0, progress.length);
+        const last = progress[progress.length - 1];
+        __self.assertEquals(3, last.totalObjects);
+        __self.assertEquals(last.totalObjects, last.receivedObjects);
+
+        __self.assertGreaterThan(0, messages.length);
+        __self.assertEquals('first commit', (await repo.findCommit(oid)).messageRaw());
+    }
+
+    async testAbortDuringTransfer() {
+        // Always asks for credentials: the signal is triggered while the clone is waiting for them.
+        const server = createServer((req, res) => {
+            res.writeHead(401, { 'WWW-Authenticate': 'Basic realm="test"' });
+            res.end();
+        });
+        await new Promise((resolve) => server.listen(0, '127.0.0.1', resolve));
+
+        const controller = new AbortController();
+        let requests = 0;
+        let error = null;
+        try {
+            await Repository.clone('http://127.0.0.1:' + server.address().port + '/repo.git', this._tmpDirName, false, {
+                credentialsCallback: () => {
+                    requests++;
+                    controller.abort();
+
+                    return Credentials.usernameAndPassword('user', 'password');
+                },
+                signal: controller.signal,
+            });
+        } catch (e) {
+            error = e;
+        } finally {
+            server.close();
+        }
+
+        __self.assertEquals(1, requests);
+        __self.assertNotNull(error);
+        __self.assertEquals('Cancelled', error.code);
+        __self.assertEquals('The operation was aborted', error.message);
+    }
+
+    async testAbortListenerIsRemovedOnSettle() {
+        const bareOpts = new InitOptions();
+        bareOpts.setBare(true);
+        await Repository.init(this._remoteDirName, bareOpts);
+
+        const controller = new AbortController();
+        const cloned = Repository.clone('file://' + this._remoteDirName, this._tmpDirName, false, { signal: controller.signal });
+        __self.assertCount(1, getEventListeners(controller.signal, 'abort'));
+
+        await cloned;
+        __self.assertCount(0, getEventListeners(controller.signal, 'abort'));
+    }
+}
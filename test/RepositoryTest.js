@@ -1,24 +1,17 @@
-import { InitOptions, Repository, RepositoryState } from '../index';
+import { FileStatus, InitOptions, Repository, RepositoryState } from '../index';
 import { randomBytes } from 'node:crypto';
 import { sep } from 'node:path';
 import { tmpdir } from 'node:os';
 
 const Filesystem = Jymfony.Component.Filesystem.Filesystem;
-const File = Jymfony.Component.Filesystem.File;
-const TestCase = Jymfony.Component.Testing.Framework.TestCase;
+const GitTestCase = Tests.GitTestCase;
 
 const fs = new Filesystem();
 
-export default class RepositoryTest extends TestCase {
-    _tmpDirName;
-
-    async beforeEach() {
-        this._tmpDirName = tmpdir() + sep + randomBytes(5).toString('base64').replace(/[^0-9a-z]/i, '-');
-        await fs.mkdir(this._tmpDirName);
-    }
-
-    async afterEach() {
-        await fs.remove(this._tmpDirName);
+export default class RepositoryTest extends GitTestCase {
+    async _createRepository() {
+        // Repositories are initialized by the tests themselves.
+        return null;
     }
 
     async testRepositoryInit() {
@@ -59,34 +52,8 @@ export default class RepositoryTest extends TestCase {
     }
 
     async testRepositoryClone() {
-        const opts = new InitOptions();
-        opts.setInitialHead('master');
-
-        const repo = await Repository.init(this._tmpDirName, opts);
-        const config = await repo.config();
-        config.setStr('user.name', 'test');
-        config.setStr('user.email', 'test@example.com');
-
-        const sig = await repo.signature();
-
-        const file = new File(this._tmpDirName + sep + 'README.md');
-        const openFile = await file.openFile('w+');
-        await openFile.fwrite(Buffer.from('Example\n'));
-        await openFile.close();
-
-        const index = await repo.index();
-        await index.addPath('README.md');
-        const tree_id = await index.writeTree();
-        const tree = await repo.findTree(tree_id);
-
-        const commit_id = await repo.createCommit(
-            'refs/heads/master',
-            sig,
-            sig,
-            "first commit",
-            tree,
-            [],
-        );
+        this._repo = await super._createRepository();
+        const commit_id = await this._commit('README.md', 'Example\n', 'first commit');
 
         __self.assertNotNull(commit_id);
 
@@ -102,80 +69,77 @@ export default class RepositoryTest extends TestCase {
         }
     }
 
-    async testRepositoryFastRebase() {
-        const opts = new InitOptions();
-        opts.setInitialHead('master');
+    async testRepositoryCloneReportsTransferProgress() {
+        this._repo = await super._createRepository();
+        await this._commit('README.md', 'Example\n', 'first commit');
 
-        const repo = await Repository.init(this._tmpDirName, opts);
-        const config = await repo.config();
-        config.setStr('user.name', 'test');
-        config.setStr('user.email', 'test@example.com');
+        const progress = [];
+        const tmpDirName = tmpdir() + sep + randomBytes(5).toString('base64').replace(/[^0-9a-z]/i, '-');
+        try {
+            await Repository.clone('file://' + this._tmpDirName, tmpDirName, false, {
+                transferProgress: (stats) => progress.push(stats),
+            });
+
+            __self.assertGreaterThan(0, progress.length);
+
+            const last = progress[progress.length - 1];
+            __self.assertEquals(3, last.totalObjects);
+            __self.assertEquals(last.totalObjects, last.receivedObjects);
+            __self.assertEquals(last.totalObjects, last.indexedObjects);
+        } finally {
+            await fs.remove(tmpDirName);
+        }
+    }
+
+    async testRepositoryCloneCanBeAborted() {
+        await Repository.init(this._tmpDirName);
 
-        const sig = await repo.signature();
+        const controller = new AbortController();
+        controller.abort();
 
-        let file = new File(this._tmpDirName + sep + 'README.md');
-        let openFile = await file.openFile('w+');
-        await openFile.fwrite(Buffer.from('Example\n'));
-        await openFile.close();
+        const tmpDirName = tmpdir() + sep + randomBytes(5).toString('base64').replace(/[^0-9a-z]/i, '-');
+        try {
+            await Repository.clone('file://' + this._tmpDirName, tmpDirName, false, { signal: controller.signal });
+            __self.fail('Clone should have been aborted');
+        } catch (e) {
+            __self.assertEquals('Cancelled', e.code);
+        } finally {
+            await fs.remove(tmpDirName);
+        }
+    }
+
+    async testRepositoryStatuses() {
+        const repo = await Repository.init(this._tmpDirName);
+        await this._writeFile('README.md', 'Example\n');
+        await this._writeFile('UNTRACKED.md', 'Untracked\n');
 
-        let index = await repo.index();
+        const index = await repo.index();
         await index.addPath('README.md');
-        let tree_id = await index.writeTree();
-        let tree = await repo.findTree(tree_id);
-
-        const commit_id = await repo.createCommit(
-            'HEAD',
-            sig,
-            sig,
-            "first commit",
-            tree,
-            [],
-        );
+
+        const statuses = await repo.statuses();
+        __self.assertCount(2, statuses);
+        __self.assertEquals('README.md', statuses[0].path);
+        __self.assertEquals(FileStatus.IndexNew, statuses[0].status);
+        __self.assertEquals('UNTRACKED.md', statuses[1].path);
+        __self.assertEquals(FileStatus.WtNew, statuses[1].status);
+
+        const filtered = await repo.statuses({ includeUntracked: false });
+        __self.assertCount(1, filtered);
+        __self.assertEquals('README.md', filtered[0].path);
+    }
+
+    async testRepositoryFastRebase() {
+        const repo = this._repo = await super._createRepository();
+        const commit_id = await this._commit('README.md', 'Example\n', 'first commit');
 
         await repo.createBranch('branch_1', commit_id, false);
         await repo.createBranch('branch_2', commit_id, false);
 
         await repo.checkout('refs/heads/branch_1');
-
-        file = new File(this._tmpDirName + sep + 'EXAMPLE.md');
-        openFile = await file.openFile('w+');
-        await openFile.fwrite(Buffer.from('Example\n'));
-        await openFile.close();
-
-        index = await repo.index();
-        await index.addPath('EXAMPLE.md');
-        tree_id = await index.writeTree();
-        tree = await repo.findTree(tree_id);
-
-        await repo.createCommit(
-            'HEAD',
-            sig,
-            sig,
-            "second commit",
-            tree,
-            [await repo.findCommit(commit_id)],
-        );
+        await this._commit('EXAMPLE.md', 'Example\n', 'second commit');
 
         await repo.checkout('refs/heads/branch_2');
-
-        file = new File(this._tmpDirName + sep + 'README.md');
-        openFile = await file.openFile('w+');
-        await openFile.fwrite(Buffer.from('Example\n\nFrom second branch'));
-        await openFile.close();
-
-        index = await repo.index();
-        await index.addPath('README.md');
-        tree_id = await index.writeTree();
-        tree = await repo.findTree(tree_id);
-
-        await repo.createCommit(
-            'HEAD',
-            sig,
-            sig,
-            "third commit",
-            tree,
-            [await repo.findCommit(commit_id)],
-        );
+        await this._commit('README.md', 'Example\n\nFrom second branch', 'third commit');
 
         await repo.fastRebase('refs/heads/branch_1');
         const lastCommit = await repo.getBranchCommit('refs/heads/branch_2');
@@ -0,0 +1,79 @@
+import { ObjectType } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class RevparseTest extends GitTestCase {
+    async testRevparseSingle() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        await repo.createTag('v1.0.0', first, await repo.signature(), 'Release 1.0.0', false);
+
+        __self.assertEquals(second.toString(), (await repo.revparseSingle('HEAD')).oid().toString());
+        __self.assertEquals(first.toString(), (await repo.revparseSingle('HEAD~1')).oid().toString());
+        __self.assertEquals(first.toString(), (await repo.revparseSingle(first.toString().substring(0, 7))).toString());
+
+        const tree = await repo.revparseSingle('v1.0.0^{tree}');
+        __self.assertEquals(ObjectType.Tree, tree.kind());
+        __self.assertEquals((await repo.findCommit(first)).getTree().oid().toString(), tree.asTree().oid().toString());
+        __self.assertEquals(ObjectType.Tag, (await repo.revparseSingle('v1.0.0')).kind());
+
+        let error = null;
+        try {
+            await repo.revparseSingle('HEAD~5');
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testRevparseExt() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        await repo.createBranch('feature', first, false);
+        await repo.createTag('v1.0.0', first, await repo.signature(), 'Release 1.0.0', false);
+
+        const head = await repo.revparseExt('master');
+        __self.assertEquals(second.toString(), head.object.oid().toString());
+        __self.assertEquals('refs/heads/master', head.reference.name());
+
+        const tag = await repo.revparseExt('v1.0.0');
+        __self.assertEquals('refs/tags/v1.0.0', tag.reference.name());
+
+        const short = await repo.revparseExt(first.toString().substring(0, 7));
+        __self.assertEquals(first.toString(), short.object.oid().toString());
+        __self.assertFalse(!! short.reference);
+
+        const config = await repo.config();
+        config.setStr('branch.feature.remote', '.');
+        config.setStr('branch.feature.merge', 'refs/heads/master');
+
+        const upstream = await repo.revparseExt('feature@{upstream}');
+        __self.assertEquals(second.toString(), upstream.object.oid().toString());
+        __self.assertEquals('refs/heads/master', upstream.reference.name());
+    }
+
+    async testRevparseRange() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+
+        const single = await repo.revparse('HEAD');
+        __self.assertEquals(second.toString(), single.from.oid().toString());
+        __self.assertFalse(!! single.to);
+        __self.assertFalse(single.range);
+        __self.assertFalse(single.mergeBase);
+
+        const range = await repo.revparse('HEAD~1..HEAD');
+        __self.assertEquals(first.toString(), range.from.oid().toString());
+        __self.assertEquals(second.toString(), range.to.oid().toString());
+        __self.assertTrue(range.range);
+        __self.assertFalse(range.mergeBase);
+
+        const symmetric = await repo.revparse('HEAD~1...HEAD');
+        __self.assertTrue(symmetric.range);
+        __self.assertTrue(symmetric.mergeBase);
+    }
+}
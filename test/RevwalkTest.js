@@ -0,0 +1,207 @@
+import { Sort } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class RevwalkTest extends GitTestCase {
+    async _walk(walk) {
+        const oids = [];
+        for (let oid = await walk.next(); oid; oid = await walk.next()) {
+            oids.push(oid.toString());
+        }
+
+        return oids;
+    }
+
+    async testPushAndHide() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        const third = await this._commit('README.md', 'Example\nThird\n', 'third commit');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.pushHead();
+        __self.assertEquals([ third, second, first ].map(String), await this._walk(walk));
+
+        await walk.push(third);
+        await walk.hide(first);
+        __self.assertEquals([ third, second ].map(String), await this._walk(walk));
+
+        await walk.pushRef('refs/heads/master');
+        await walk.hideRef('refs/heads/master');
+        __self.assertEquals([], await this._walk(walk));
+    }
+
+    async testGlobs() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const left = await this._commitOn([ first ], 'left');
+        const right = await this._commitOn([ first ], 'right');
+        await repo.createBranch('feature/left', left, false);
+        await repo.createBranch('feature/right', right, false);
+
+        const walk = await repo.createRevWalk();
+        await walk.pushGlob('heads/feature/*');
+        __self.assertEquals([ first, left, right ].map(String).sort(), (await this._walk(walk)).sort());
+
+        await walk.pushGlob('heads/*');
+        await walk.hideGlob('heads/mast*');
+        __self.assertEquals([ left, right ].map(String).sort(), (await this._walk(walk)).sort());
+    }
+
+    async testSimplifyFirstParent() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const main = await this._commitOn([ first ], 'main');
+        const side = await this._commitOn([ first ], 'side');
+        const merge = await this._commitOn([ main, side ], 'merge');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.push(merge);
+        await walk.simplifyFirstParent();
+        __self.assertEquals([ merge, main, first ].map(String), await this._walk(walk));
+    }
+
+    async testPathspecs() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('lib.rs', 'fn main() {}\n', 'add lib');
+        await this._commit('README.md', 'Example\nSecond\n', 'update readme');
+        const fourth = await this._commit('lib.rs', 'fn main() { }\n', 'update lib');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.pushHead();
+        await walk.setPathspecs([ '*.rs' ]);
+        __self.assertEquals([ fourth, second ].map(String), await this._walk(walk));
+
+        await walk.pushHead();
+        await walk.setPathspecs([ 'README.md' ]);
+        const readme = await this._walk(walk);
+        __self.assertCount(2, readme);
+        __self.assertEquals(first.toString(), readme[1]);
+
+        await walk.pushHead();
+        await walk.setPathspecs([]);
+        __self.assertCount(4, await this._walk(walk));
+    }
+
+    async testPathspecsSkipTreesameMerges() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const lib = await this._commit('lib.rs', 'fn main() {}\n', 'add lib');
+        const merge = await this._commitOn([ lib, first ], 'merge');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.push(merge);
+        await walk.setPathspecs([ 'lib.rs' ]);
+        __self.assertEquals([ lib ].map(String), await this._walk(walk));
+
+        await walk.push(merge);
+        await walk.setPathspecs([ 'README.md' ]);
+        __self.assertEquals([ first ].map(String), await this._walk(walk));
+    }
+
+    async testPathspecsFollowFirstParent() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const side = await this._commit('lib.rs', 'fn main() {}\n', 'side');
+        const main = await this._commitOn([ first ], 'main');
+
+        const sig = await repo.signature();
+        const parents = [ await repo.findCommit(main), await repo.findCommit(side) ];
+        const merge = await repo.createCommit(null, sig, sig, 'merge', parents[1].getTree(), parents);
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.push(merge);
+        await walk.setPathspecs([ 'lib.rs' ]);
+        __self.assertEquals([ side ].map(String), await this._walk(walk));
+
+        await walk.push(merge);
+        await walk.simplifyFirstParent();
+        __self.assertEquals([ merge ].map(String), await this._walk(walk));
+
+        await walk.push(merge);
+        __self.assertEquals([ side ].map(String), await this._walk(walk));
+    }
+
+    async testNextBatch() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+        const third = await this._commit('README.md', 'Example\nThird\n', 'third commit');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.pushHead();
+
+        __self.assertEquals([ third, second ].map(String), (await walk.nextBatch(2)).map(String));
+        __self.assertEquals([ first ].map(String), (await walk.nextBatch(2)).map(String));
+        __self.assertEquals([], await walk.nextBatch(2));
+
+        await walk.pushHead();
+        const summaries = await walk.nextSummaryBatch(10);
+        __self.assertEquals([ 'third commit', 'second commit', 'first commit' ], summaries.map((s) => s.summary));
+        __self.assertEquals(third.toString(), summaries[0].oid.toString());
+        __self.assertEquals([ second.toString() ], summaries[0].parents.map(String));
+        __self.assertEquals([], summaries[2].parents);
+        __self.assertEquals('test', summaries[0].author.name());
+        __self.assertEquals((await repo.findCommit(third)).committer().time().time, summaries[0].time.time);
+    }
+
+    async testAsyncIterator() {
+        const repo = this._repo;
+        const first = await this._commit('README.md', 'Example\n', 'first commit');
+        const second = await this._commit('README.md', 'Example\nSecond\n', 'second commit');
+
+        const walk = await repo.createRevWalk();
+        await walk.sort([ Sort.Topological ]);
+        await walk.pushHead();
+
+        const oids = [];
+        for await (const oid of walk) {
+            oids.push(oid.toString());
+        }
+
+        __self.assertEquals([ second, first ].map(String), oids);
+
+        await walk.pushHead();
+        await walk.hide(first);
+        const summaries = [];
+        for await (const summary of walk.summaries()) {
+            summaries.push(summary.summary);
+        }
+
+        __self.assertEquals([ 'second commit' ], summaries);
+
+        await walk.pushHead();
+        const iterated = [];
+        for await (const oid of walk.oids()) {
+            iterated.push(oid.toString());
+        }
+
+        __self.assertEquals([ second, first ].map(String), iterated);
+    }
+
+    async testAsyncIteratorOnLongHistory() {
+        const repo = this._repo;
+        let parent = await this._commit('README.md', 'Example\n', 'first commit');
+        for (let i = 0; i < 600; i++) {
+            parent = await this._commitOn([ parent ], 'commit ' + i);
+        }
+
+        const walk = await repo.createRevWalk();
+        await walk.push(parent);
+
+        let count = 0;
+        for await (const oid of walk) {
+            __self.assertNotNull(oid);
+            count++;
+        }
+
+        __self.assertEquals(601, count);
+    }
+}
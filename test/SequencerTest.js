@@ -0,0 +1,129 @@
+import { Repository, RepositoryState, SequencerAction } from '../index';
+import { existsSync } from 'node:fs';
+import { sep } from 'node:path';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class SequencerTest extends GitTestCase {
+    async _prepareBranches() {
+        const repo = this._repo;
+        const base = await this._commit('README.md', 'Example\n', 'first commit');
+        await repo.createBranch('feature', base, false);
+        const master = await this._commit('README.md', 'Ours\n', 'master commit');
+
+        await repo.checkout('refs/heads/feature');
+        await this._commit('FEATURE.md', 'Feature\n', 'feature commit');
+        const conflicting = await this._commit('README.md', 'Theirs\n', 'conflicting commit');
+        await this._commit('OTHER.md', 'Other\n', 'other commit');
+        await repo.checkout('refs/heads/master');
+
+        return { master, conflicting };
+    }
+
+    async _messages(count) {
+        const messages = [];
+        let commit = await this._repo.findCommit((await this._repo.head()).target());
+        while (commit && messages.length < count) {
+            messages.push(commit.messageRaw().trim());
+            [ commit ] = commit.getParents();
+        }
+
+        return messages;
+    }
+
+    async testCherrypickSequencePausesOnConflict() {
+        const repo = this._repo;
+        const { conflicting } = await this._prepareBranches();
+
+        const sequencer = await repo.cherrypickSequence('master..feature');
+        __self.assertEquals(RepositoryState.CherryPickSequence, await repo.state());
+
+        const current = await sequencer.current();
+        __self.assertEquals(SequencerAction.Pick, current.action);
+        __self.assertEquals(conflicting.toString(), current.id.toString());
+        __self.assertEquals('conflicting commit', current.summary);
+        __self.assertCount(2, await sequencer.todo());
+
+        await this._writeFile('README.md', 'Resolved\n');
+        const index = await repo.index();
+        await index.addPath('README.md');
+        await index.write();
+
+        __self.assertNull(await sequencer.continue());
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals(
+            [ 'other commit', 'conflicting commit', 'feature commit', 'master commit' ],
+            await this._messages(4),
+        );
+    }
+
+    async testSequencerSkip() {
+        const repo = this._repo;
+        await this._prepareBranches();
+
+        const sequencer = await repo.cherrypickSequence('master..feature');
+        __self.assertNull(await sequencer.skip());
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals([ 'other commit', 'feature commit', 'master commit' ], await this._messages(3));
+        __self.assertTrue(existsSync(this._tmpDirName + sep + 'OTHER.md'));
+    }
+
+    async testSequencerResumeAndAbort() {
+        const repo = this._repo;
+        const { master } = await this._prepareBranches();
+
+        await repo.cherrypickSequence('master..feature');
+
+        const reopened = await (await Repository.open(this._tmpDirName)).openSequencer();
+        __self.assertEquals('conflicting commit', (await reopened.current()).summary);
+        await reopened.abort();
+
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertEquals(master.toString(), (await repo.head()).target().toString());
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'FEATURE.md'));
+    }
+
+    async testFailedStartCleansUpSequence() {
+        const repo = this._repo;
+        const { master } = await this._prepareBranches();
+
+        await repo.checkout('refs/heads/feature');
+        const feature = (await repo.head()).target();
+        const picked = await this._commit('PICKED.md', 'Picked\n', 'picked commit');
+        const merge = await this._commitOn([ picked, master ], 'merge commit');
+        await repo.checkout('refs/heads/master');
+        await repo.createBranch('feature', merge, true);
+
+        // Merge commits cannot be picked without a mainline.
+        let error = null;
+        try {
+            await repo.cherrypickSequence(feature.toString() + '..feature');
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+        __self.assertEquals(RepositoryState.Clean, await repo.state());
+        __self.assertFalse(existsSync(this._tmpDirName + sep + '.git' + sep + 'sequencer'));
+        __self.assertEquals(master.toString(), (await repo.head()).target().toString());
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'PICKED.md'));
+    }
+
+    async testRevertSequence() {
+        const repo = this._repo;
+        await this._commit('README.md', 'Example\n', 'first commit');
+        await this._commit('FIRST.md', 'First\n', 'second commit');
+        await this._commit('SECOND.md', 'Second\n', 'third commit');
+
+        const sequencer = await repo.revertSequence('HEAD~2..HEAD');
+        __self.assertNull(await sequencer.current());
+
+        __self.assertEquals(
+            [ 'Revert "second commit"', 'Revert "third commit"' ],
+            (await this._messages(2)).map((m) => m.split('\n')[0]),
+        );
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'FIRST.md'));
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'SECOND.md'));
+    }
+}
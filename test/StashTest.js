@@ -0,0 +1,69 @@
+import { ResetType, StashApplyProgress } from '../index';
+import { existsSync, readFileSync } from 'node:fs';
+import { sep } from 'node:path';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class StashTest extends GitTestCase {
+    async _read(fileName) {
+        return readFileSync(this._tmpDirName + sep + fileName, 'utf-8');
+    }
+
+    async testStashSaveListAndDrop() {
+        const repo = this._repo;
+        await this._commit('README.md', 'Example\n', 'first commit');
+        const sig = await repo.signature();
+
+        await this._writeFile('README.md', 'Changed\n');
+        const first = await repo.stashSave(sig, 'first stash');
+        __self.assertEquals('Example\n', await this._read('README.md'));
+
+        await this._writeFile('UNTRACKED.md', 'Untracked\n');
+        await repo.stashSave(sig, 'second stash', { includeUntracked: true });
+        __self.assertFalse(existsSync(this._tmpDirName + sep + 'UNTRACKED.md'));
+
+        let list = await repo.stashList();
+        __self.assertEquals([ 0, 1 ], list.map((e) => e.index));
+        __self.assertEquals('On master: second stash', list[0].message);
+        __self.assertEquals('On master: first stash', list[1].message);
+        __self.assertEquals(first.toString(), list[1].oid.toString());
+
+        await repo.stashDrop(0);
+        list = await repo.stashList();
+        __self.assertCount(1, list);
+        __self.assertEquals(first.toString(), list[0].oid.toString());
+    }
+
+    async testStashKeepIndex() {
+        const repo = this._repo;
+        await this._commit('README.md', 'Example\n', 'first commit');
+
+        await this._writeFile('README.md', 'Staged\n');
+        await (await repo.index()).addPath('README.md');
+        await (await repo.index()).write();
+
+        await repo.stashSave(await repo.signature(), null, { keepIndex: true });
+        __self.assertEquals('Staged\n', await this._read('README.md'));
+    }
+
+    async testStashApplyAndPop() {
+        const repo = this._repo;
+        await this._commit('README.md', 'Example\n', 'first commit');
+
+        await this._writeFile('README.md', 'Changed\n');
+        await repo.stashSave(await repo.signature(), 'stash');
+
+        const stages = [];
+        await repo.stashApply(0, { progress: (stage) => stages.push(stage) });
+        __self.assertEquals('Changed\n', await this._read('README.md'));
+        __self.assertEquals(StashApplyProgress.Done, stages[stages.length - 1]);
+        __self.assertCount(1, await repo.stashList());
+
+        await repo.reset(await repo.findCommit((await repo.head()).target()), ResetType.Hard);
+        __self.assertEquals('Example\n', await this._read('README.md'));
+
+        await repo.stashPop(0, { reinstateIndex: true });
+        __self.assertEquals('Changed\n', await this._read('README.md'));
+        __self.assertCount(0, await repo.stashList());
+    }
+}
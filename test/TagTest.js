@@ -0,0 +1,57 @@
+import { ObjectType } from '../index';
+
+const GitTestCase = Tests.GitTestCase;
+
+export default class TagTest extends GitTestCase {
+    async testCreateAnnotatedTag() {
+        const repo = this._repo;
+        const commit = await this._commit('README.md', 'Example\n', 'first commit');
+        const tagger = await repo.signature();
+
+        const oid = await repo.createTag('v1.0.0', commit, tagger, 'Release 1.0.0\n', false);
+        const tag = await repo.findTag(oid);
+
+        __self.assertEquals(oid.toString(), tag.oid().toString());
+        __self.assertEquals('v1.0.0', tag.name());
+        __self.assertEquals('Release 1.0.0\n', tag.message());
+        __self.assertEquals('test', tag.tagger().name());
+        __self.assertEquals(commit.toString(), tag.targetId().toString());
+        __self.assertEquals(ObjectType.Commit, tag.targetType());
+        __self.assertEquals(commit.toString(), tag.peel().asCommit().oid().toString());
+        __self.assertNull(tag.peel().asTag());
+
+        let error = null;
+        try {
+            await repo.createTag('v1.0.0', commit, tagger, 'Again', false);
+        } catch (e) {
+            error = e;
+        }
+
+        __self.assertNotNull(error);
+    }
+
+    async testCreateLightweightTag() {
+        const repo = this._repo;
+        const commit = await this._commit('README.md', 'Example\n', 'first commit');
+
+        const oid = await repo.createLightweightTag('light', commit, false);
+        __self.assertEquals(commit.toString(), oid.toString());
+        __self.assertEquals([ 'light' ], await repo.tagNames());
+    }
+
+    async testListAndDeleteTags() {
+        const repo = this._repo;
+        const commit = await this._commit('README.md', 'Example\n', 'first commit');
+        const tagger = await repo.signature();
+
+        await repo.createTag('v1.0.0', commit, tagger, 'Release 1.0.0', false);
+        await repo.createTag('v1.1.0', commit, tagger, 'Release 1.1.0', false);
+        await repo.createLightweightTag('v2.0.0', commit, false);
+
+        __self.assertEquals([ 'v1.0.0', 'v1.1.0', 'v2.0.0' ], (await repo.tagNames()).sort());
+        __self.assertEquals([ 'v1.0.0', 'v1.1.0' ], (await repo.tagNames('v1.*')).sort());
+
+        await repo.deleteTag('v1.0.0');
+        __self.assertEquals([ 'v1.1.0', 'v2.0.0' ], (await repo.tagNames()).sort());
+    }
+}